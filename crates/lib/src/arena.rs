@@ -38,6 +38,10 @@ impl std::error::Error for ArenaAllocError {}
 pub struct Arena<'a> {
     start: Cell<*mut u8>,
     end: Cell<*mut u8>,
+    /// The end of the underlying storage.
+    top: *mut u8,
+    /// The lowest `end` ever reached, used to track the high water mark.
+    low: Cell<*mut u8>,
     _marker: PhantomData<&'a mut [u8]>,
 }
 
@@ -52,10 +56,47 @@ impl<'a> Arena<'a> {
         Self {
             start: Cell::new(range.start),
             end: Cell::new(range.end),
+            top: range.end,
+            low: Cell::new(range.end),
             _marker: PhantomData,
         }
     }
 
+    /// Get the largest number of bytes which have been in use at the same
+    /// time, including any padding needed for alignment.
+    ///
+    /// Memory freed by [Arena::scope] still counts towards the high water
+    /// mark. This is useful for tuning the size of the storage, which is also
+    /// logged when the arena is dropped in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// #[repr(align(8))]
+    /// struct Storage([u8; 64]);
+    ///
+    /// let mut storage = Storage([0; 64]);
+    /// let arena = Arena::new(&mut storage.0);
+    /// assert_eq!(arena.high_water(), 0);
+    ///
+    /// arena.alloc(1u8)?;
+    /// assert_eq!(arena.high_water(), 1);
+    ///
+    /// // Three bytes of padding to align the `u32`.
+    /// arena.alloc(2u32)?;
+    /// assert_eq!(arena.high_water(), 8);
+    ///
+    /// arena.scope(|arena| arena.alloc_default_slice::<u64>(4).map(|_| ()))?;
+    /// assert_eq!(arena.high_water(), 40);
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub fn high_water(&self) -> usize {
+        addr(self.top) - addr(self.low.get())
+    }
+
     /// Allocate a new object of the given type.
     ///
     /// ```
@@ -120,6 +161,131 @@ impl<'a> Arena<'a> {
         })
     }
 
+    /// Allocate a slice of `len` elements initialized to their default
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut data = [0; 128];
+    /// let arena = Arena::new(&mut data);
+    ///
+    /// let slice = arena.alloc_default_slice::<u32>(10)?;
+    /// assert_eq!(slice, &[0; 10]);
+    ///
+    /// slice[2] = 4;
+    /// slice[9] = 8;
+    /// assert_eq!(slice, &[0, 0, 4, 0, 0, 0, 0, 0, 0, 8]);
+    ///
+    /// assert!(arena.alloc_default_slice::<u32>(100).is_err());
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn alloc_default_slice<T>(&self, len: usize) -> Result<&mut [T], ArenaAllocError>
+    where
+        T: Default + Copy,
+    {
+        let layout = Layout::array::<T>(len).unwrap();
+
+        let mem = if layout.size() == 0 {
+            ptr::NonNull::<T>::dangling().as_ptr()
+        } else {
+            self.alloc_raw(layout)? as *mut T
+        };
+
+        unsafe {
+            for n in 0..len {
+                // Write into uninitialized memory.
+                ptr::write(mem.add(n), T::default());
+            }
+
+            Ok(slice::from_raw_parts_mut(mem, len))
+        }
+    }
+
+    /// Run `f` with a scratch arena, where everything allocated inside of the
+    /// scope is freed once it returns.
+    ///
+    /// The scratch arena is handed all the memory which is currently free in
+    /// this arena. In the meantime this arena can't allocate anything, so
+    /// earlier allocations can be held on to across the scope, and scopes can
+    /// be nested. Since the scratch arena only lives for the duration of `f`,
+    /// nothing allocated from it can be returned from the scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut data = [0; 16];
+    /// let arena = Arena::new(&mut data);
+    ///
+    /// let sum = arena.scope(|arena| {
+    ///     let slice = arena.alloc_default_slice::<u32>(4)?;
+    ///     slice.copy_from_slice(&[1, 2, 3, 4]);
+    ///     assert!(arena.alloc(0u32).is_err());
+    ///     Ok::<_, Error>(slice.iter().sum::<u32>())
+    /// })?;
+    ///
+    /// assert_eq!(sum, 10);
+    /// // The scratch space has been returned to the arena.
+    /// assert!(arena.alloc_default_slice::<u32>(4).is_ok());
+    /// # Ok::<_, Error>(())
+    /// ```
+    ///
+    /// Allocations from an outer scope stay valid across nested scopes:
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut data = [0; 32];
+    /// let arena = Arena::new(&mut data);
+    ///
+    /// let outer = arena.alloc(1u32)?;
+    ///
+    /// arena.scope(|arena| {
+    ///     let middle = arena.alloc(2u32)?;
+    ///
+    ///     arena.scope(|arena| {
+    ///         let inner = arena.alloc_default_slice::<u32>(2)?;
+    ///         inner.fill(3);
+    ///         *outer += inner.iter().sum::<u32>();
+    ///         *middle += 1;
+    ///         Ok::<_, Error>(())
+    ///     })?;
+    ///
+    ///     *outer += *middle;
+    ///     Ok::<_, Error>(())
+    /// })?;
+    ///
+    /// assert_eq!(*outer, 10);
+    /// assert!(arena.alloc_default_slice::<u32>(3).is_ok());
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn scope<R>(&self, f: impl FnOnce(&Arena<'_>) -> R) -> R {
+        let start = self.start.get();
+        let end = self.end.get();
+
+        // The scratch arena owns the free region until the end of the scope,
+        // during which this arena has no memory to hand out. It has no
+        // storage of its own to release, so it's never dropped, which also
+        // keeps it from logging its high water mark.
+        let scratch = mem::ManuallyDrop::new(Arena {
+            start: Cell::new(start),
+            end: Cell::new(end),
+            top: self.top,
+            low: Cell::new(self.low.get()),
+            _marker: PhantomData,
+        });
+
+        self.start.set(end);
+        let output = f(&scratch);
+        self.start.set(start);
+        self.low.set(scratch.low.get());
+        output
+    }
+
     #[inline]
     fn alloc_raw_without_grow(&self, layout: Layout) -> Option<*mut u8> {
         let start = addr(self.start.get());
@@ -137,6 +303,11 @@ impl<'a> Arena<'a> {
 
         let new_end = with_addr(old_end, new_end);
         self.end.set(new_end);
+
+        if new_end < self.low.get() {
+            self.low.set(new_end);
+        }
+
         Some(new_end)
     }
 
@@ -154,6 +325,16 @@ impl<'a> Arena<'a> {
     }
 }
 
+impl Drop for Arena<'_> {
+    fn drop(&mut self) {
+        if cfg!(debug_assertions) {
+            let high_water = self.high_water();
+            let capacity = addr(self.top) - addr(self.start.get());
+            log::debug!("arena high water mark: {high_water} of {capacity} bytes");
+        }
+    }
+}
+
 #[inline]
 fn addr(this: *mut u8) -> usize {
     this as usize
@@ -176,6 +357,46 @@ pub struct AllocIter<'a, T> {
 }
 
 impl<'a, T> AllocIter<'a, T> {
+    /// The total number of elements that can be written into the slice.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test if the slice has no room for any elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of elements that can still be written before
+    /// [AllocIter::write] errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut data = [0; 128];
+    /// let arena = Arena::new(&mut data);
+    ///
+    /// let mut it = arena.alloc_iter(2)?;
+    /// assert_eq!(it.len(), 2);
+    /// assert_eq!(it.remaining(), 2);
+    /// it.write(4u32)?;
+    /// assert_eq!(it.remaining(), 1);
+    /// it.write(8u32)?;
+    /// assert_eq!(it.remaining(), 0);
+    /// assert!(it.write(16u32).is_err());
+    /// assert_eq!(it.len(), 2);
+    /// assert_eq!(it.finish(), &[4, 8]);
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.len - self.index
+    }
+
     /// Write the next element into the slice.
     pub fn write(&mut self, object: T) -> Result<(), ArenaWriteSliceOutOfBounds> {
         // Sanity check is necessary to ensure memory safety.
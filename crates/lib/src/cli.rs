@@ -1,22 +1,32 @@
 //! CLI helpers.
 
 mod bencher;
+mod checker;
 pub(crate) mod error;
 mod output;
 mod output_eq;
+mod phases;
+mod progress;
 mod stdout_logger;
 
 use core::fmt;
+use core::num::NonZeroUsize;
 use core::ops::AddAssign;
 use core::time::Duration;
+use std::ffi::OsString;
+use std::io::IsTerminal;
+use std::path::PathBuf;
 
 use anyhow::{anyhow, bail, Context, Result};
 use serde::{Deserialize, Serialize};
 
 pub use self::bencher::Bencher;
+pub use self::checker::Checker;
 pub use self::error::error_context;
 use self::output::{Output, OutputKind};
-pub use self::output_eq::OutputEq;
+pub use self::output_eq::{Approx, OutputEq, F32_EPSILON, F64_EPSILON};
+pub use self::phases::Phases;
+pub use self::progress::Progress;
 
 static STDOUT_LOGGER: stdout_logger::StdoutLogger = stdout_logger::StdoutLogger;
 
@@ -28,6 +38,31 @@ pub enum Mode {
     Default,
     /// Run as benchmark.
     Bench,
+    /// Only check the answer against the expected value.
+    Check,
+}
+
+/// When to color normal output.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// Color output if the stream it is written to is a terminal.
+    #[default]
+    Auto,
+    /// Always color output.
+    Always,
+    /// Never color output.
+    Never,
+}
+
+/// Fixed unit to print timings in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    /// Milliseconds.
+    Ms,
+    /// Microseconds.
+    Us,
+    /// Nanoseconds.
+    Ns,
 }
 
 /// Input options.
@@ -44,14 +79,82 @@ pub struct Opts {
     /// Bench period.
     time_limit: Option<u64>,
     /// Number of iterations to run bench function.
-    iter: Option<usize>,
+    iter: Option<NonZeroUsize>,
+    /// Minimum number of samples to take in a time-limited bench.
+    min_samples: Option<usize>,
+    /// Number of items processed per iteration, used to report throughput.
+    items: Option<u64>,
+    /// Print per-phase timings recorded through [Phases].
+    profile: bool,
+    /// Path to a saved report to compare the benchmark against.
+    baseline: Option<PathBuf>,
+    /// Path to write the benchmark report to.
+    output: Option<PathBuf>,
+    /// Number of times to run the solution outside of benchmarking.
+    repeat: Option<usize>,
+    /// When to color normal output.
+    color: Color,
+    /// Fixed unit to print report timings in.
+    time_unit: Option<TimeUnit>,
 }
 
 impl Opts {
-    /// Parse CLI options.
+    /// Parse CLI options from the process arguments and set up logging.
+    ///
+    /// The `AOC_WARMUP`, `AOC_TIME_LIMIT` and `AOC_ITER` environment variables
+    /// are used as defaults for their corresponding flags, see
+    /// [Opts::from_args_with_env].
     pub fn parse() -> Result<Self> {
+        let opts =
+            Self::from_args_with_env(std::env::args_os().skip(1), |key| std::env::var_os(key))?;
+
+        if !opts.json {
+            log::set_max_level(log::LevelFilter::Info);
+            log::set_logger(&STDOUT_LOGGER)
+                .map_err(|error| anyhow!("failed to set log: {error}"))?;
+        }
+
+        if opts.profile {
+            let _ = phases::PROFILE.set(opts.output_kind());
+        }
+
+        if matches!(opts.mode, Mode::Default) {
+            let _ = progress::PROGRESS.set(opts.output_kind_for(&std::io::stderr()));
+        }
+
+        Ok(opts)
+    }
+
+    /// Parse CLI options from the given arguments, excluding the program name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::cli::{Mode, Opts};
+    ///
+    /// let opts = Opts::from_args(["--bench", "--iter", "3"].map(Into::into))?;
+    /// assert!(matches!(opts.mode, Mode::Bench));
+    ///
+    /// let Err(error) = Opts::from_args(["--bench", "--bench"].map(Into::into)) else {
+    ///     panic!("expected error");
+    /// };
+    /// assert_eq!(error.to_string(), "duplicate `--bench` arguments");
+    ///
+    /// let Err(error) = Opts::from_args(["--unknown"].map(Into::into)) else {
+    ///     panic!("expected error");
+    /// };
+    /// assert_eq!(error.to_string(), "unsupported argument: --unknown");
+    ///
+    /// assert!(Opts::from_args(["--iter", "many"].map(Into::into)).is_err());
+    /// assert!(Opts::from_args(["--iter", "0"].map(Into::into)).is_err());
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn from_args<I>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = OsString>,
+    {
         let mut opts = Self::default();
-        let mut it = std::env::args_os().skip(1);
+        let mut it = args.into_iter();
 
         while let Some(arg) = it.next() {
             let Some(arg) = arg.to_str() else {
@@ -60,12 +163,23 @@ impl Opts {
 
             match arg {
                 "--bench" => {
-                    if !matches!(opts.mode, Mode::Default) {
-                        bail!("duplicate `--bench` arguments");
+                    match opts.mode {
+                        Mode::Default => {}
+                        Mode::Bench => bail!("duplicate `--bench` arguments"),
+                        Mode::Check => bail!("`--bench` cannot be combined with `--check`"),
                     }
 
                     opts.mode = Mode::Bench;
                 }
+                "--check" => {
+                    match opts.mode {
+                        Mode::Default => {}
+                        Mode::Bench => bail!("`--check` cannot be combined with `--bench`"),
+                        Mode::Check => bail!("duplicate `--check` arguments"),
+                    }
+
+                    opts.mode = Mode::Check;
+                }
                 "--verbose" => {
                     opts.verbose = true;
                 }
@@ -94,6 +208,67 @@ impl Opts {
                         .context("missing string argument to `--iter`")?;
                     opts.iter = Some(iter.parse().context("bad argument to `--iter`")?);
                 }
+                "--min-samples" => {
+                    let min_samples = it.next().context("missing argument to `--min-samples`")?;
+                    let min_samples = min_samples
+                        .to_str()
+                        .context("missing string argument to `--min-samples`")?;
+                    opts.min_samples = Some(
+                        min_samples
+                            .parse()
+                            .context("bad argument to `--min-samples`")?,
+                    );
+                }
+                "--items" => {
+                    let items = it.next().context("missing argument to `--items`")?;
+                    let items = items
+                        .to_str()
+                        .context("missing string argument to `--items`")?;
+                    opts.items = Some(items.parse().context("bad argument to `--items`")?);
+                }
+                "--baseline" => {
+                    let baseline = it.next().context("missing argument to `--baseline`")?;
+                    opts.baseline = Some(PathBuf::from(baseline));
+                }
+                "--output" => {
+                    let output = it.next().context("missing argument to `--output`")?;
+                    opts.output = Some(PathBuf::from(output));
+                }
+                "--repeat" => {
+                    let repeat = it.next().context("missing argument to `--repeat`")?;
+                    let repeat = repeat
+                        .to_str()
+                        .context("missing string argument to `--repeat`")?;
+                    opts.repeat = Some(repeat.parse().context("bad argument to `--repeat`")?);
+                }
+                "--color" => {
+                    let color = it.next().context("missing argument to `--color`")?;
+
+                    opts.color = match color.to_str() {
+                        Some("auto") => Color::Auto,
+                        Some("always") => Color::Always,
+                        Some("never") => Color::Never,
+                        _ => {
+                            bail!("bad argument to `--color`, expected `auto`, `always` or `never`")
+                        }
+                    };
+                }
+                "--no-color" => {
+                    opts.color = Color::Never;
+                }
+                "--time-unit" => {
+                    let unit = it.next().context("missing argument to `--time-unit`")?;
+
+                    opts.time_unit = Some(match unit.to_str() {
+                        Some("ms") => TimeUnit::Ms,
+                        Some("us") => TimeUnit::Us,
+                        Some("ns") => TimeUnit::Ns,
+                        _ => bail!("bad argument to `--time-unit`, expected `ms`, `us` or `ns`"),
+                    });
+                }
+                "--profile" => {
+                    opts.profile = true;
+                }
                 "--json" => {
                     opts.json = true;
                 }
@@ -106,13 +281,201 @@ impl Opts {
             }
         }
 
-        if !opts.json {
-            log::set_max_level(log::LevelFilter::Info);
-            log::set_logger(&STDOUT_LOGGER)
-                .map_err(|error| anyhow!("failed to set log: {error}"))?;
+        Ok(opts)
+    }
+
+    /// Parse CLI options from the given arguments, excluding the program name,
+    /// using `env` to look up environment variables.
+    ///
+    /// The `AOC_WARMUP`, `AOC_TIME_LIMIT` and `AOC_ITER` variables are used as
+    /// defaults for `--warmup`, `--time-limit` and `--iter` respectively, but
+    /// flags always take precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::ffi::OsString;
+    /// use lib::cli::Opts;
+    ///
+    /// let env = HashMap::from([("AOC_ITER", OsString::from("5"))]);
+    /// let env = |key: &str| env.get(key).cloned();
+    ///
+    /// let opts = Opts::from_args_with_env([], env)?;
+    /// assert_eq!(opts.iter(), Some(5));
+    ///
+    /// // The flag takes precedence over the environment.
+    /// let opts = Opts::from_args_with_env(["--iter", "3"].map(Into::into), env)?;
+    /// assert_eq!(opts.iter(), Some(3));
+    ///
+    /// let env = HashMap::from([("AOC_ITER", OsString::from("many"))]);
+    /// let env = |key: &str| env.get(key).cloned();
+    ///
+    /// let Err(error) = Opts::from_args_with_env([], env) else {
+    ///     panic!("expected error");
+    /// };
+    /// assert_eq!(error.to_string(), "bad value in `AOC_ITER`");
+    ///
+    /// // Since the flag takes precedence, the environment isn't consulted.
+    /// assert!(Opts::from_args_with_env(["--iter", "3"].map(Into::into), env).is_ok());
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn from_args_with_env<I, E>(args: I, env: E) -> Result<Self>
+    where
+        I: IntoIterator<Item = OsString>,
+        E: Fn(&str) -> Option<OsString>,
+    {
+        let mut opts = Self::from_args(args)?;
+
+        if opts.warmup.is_none() {
+            opts.warmup = from_env(&env, "AOC_WARMUP")?;
         }
 
-        Ok(opts)
+        if opts.time_limit.is_none() {
+            opts.time_limit = from_env(&env, "AOC_TIME_LIMIT")?;
+        }
+
+        if opts.iter.is_none() {
+            opts.iter = from_env(&env, "AOC_ITER")?;
+        }
+
+        return Ok(opts);
+
+        fn from_env<T>(env: impl Fn(&str) -> Option<OsString>, key: &str) -> Result<Option<T>>
+        where
+            T: std::str::FromStr,
+            T::Err: std::error::Error + Send + Sync + 'static,
+        {
+            let Some(value) = env(key) else {
+                return Ok(None);
+            };
+
+            let value = value
+                .to_str()
+                .with_context(|| format!("non-utf8 value in `{key}`"))?;
+
+            let value = value
+                .parse()
+                .with_context(|| format!("bad value in `{key}`"))?;
+
+            Ok(Some(value))
+        }
+    }
+
+    /// Number of timed iterations to run when benchmarking, as specified with
+    /// `--iter` or `AOC_ITER`. If unset, the benchmark is time-limited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::cli::Opts;
+    ///
+    /// let opts = Opts::from_args([])?;
+    /// assert_eq!(opts.iter(), None);
+    ///
+    /// let opts = Opts::from_args(["--iter", "3"].map(Into::into))?;
+    /// assert_eq!(opts.iter(), Some(3));
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn iter(&self) -> Option<usize> {
+        self.iter.map(NonZeroUsize::get)
+    }
+
+    /// Number of times the solution should be run in the default mode, as
+    /// specified with `--repeat`. Defaults to `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::cli::Opts;
+    ///
+    /// let opts = Opts::from_args([])?;
+    /// assert_eq!(opts.repeat(), 1);
+    ///
+    /// let opts = Opts::from_args(["--repeat", "3"].map(Into::into))?;
+    /// assert_eq!(opts.repeat(), 3);
+    ///
+    /// assert!(Opts::from_args(["--repeat"].map(Into::into)).is_err());
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn repeat(&self) -> usize {
+        self.repeat.unwrap_or(1)
+    }
+
+    /// When normal output should be colored, as specified with `--color` or
+    /// `--no-color`. Defaults to [Color::Auto].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::cli::{Color, Opts};
+    ///
+    /// let opts = Opts::from_args([])?;
+    /// assert_eq!(opts.color(), Color::Auto);
+    ///
+    /// let opts = Opts::from_args(["--color", "always"].map(Into::into))?;
+    /// assert_eq!(opts.color(), Color::Always);
+    ///
+    /// let opts = Opts::from_args(["--no-color"].map(Into::into))?;
+    /// assert_eq!(opts.color(), Color::Never);
+    ///
+    /// assert!(Opts::from_args(["--color", "sometimes"].map(Into::into)).is_err());
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Fixed unit to print report timings in, as specified with
+    /// `--time-unit`. By default timings are scaled automatically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::cli::{Opts, TimeUnit};
+    ///
+    /// let opts = Opts::from_args([])?;
+    /// assert_eq!(opts.time_unit(), None);
+    ///
+    /// let opts = Opts::from_args(["--time-unit", "us"].map(Into::into))?;
+    /// assert_eq!(opts.time_unit(), Some(TimeUnit::Us));
+    ///
+    /// assert!(Opts::from_args(["--time-unit", "s"].map(Into::into)).is_err());
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn time_unit(&self) -> Option<TimeUnit> {
+        self.time_unit
+    }
+
+    /// Construct a handle for reporting the progress of a long-running
+    /// solution.
+    pub fn progress(&self) -> Progress {
+        Progress::with_kind(Some(self.output_kind_for(&std::io::stderr())))
+    }
+
+    /// Get the kind of output to use for stdout.
+    pub(crate) fn output_kind(&self) -> OutputKind {
+        self.output_kind_for(&std::io::stdout())
+    }
+
+    /// Get the kind of output to use when writing to `stream`, which by
+    /// default is only colored if it's a terminal.
+    pub(crate) fn output_kind_for(&self, stream: &impl IsTerminal) -> OutputKind {
+        if self.json {
+            return OutputKind::Json;
+        }
+
+        let color = match self.color {
+            Color::Auto => stream.is_terminal(),
+            Color::Always => true,
+            Color::Never => false,
+        };
+
+        if color {
+            OutputKind::Color
+        } else {
+            OutputKind::Normal
+        }
     }
 }
 
@@ -123,7 +486,7 @@ pub struct Percentiles {
 
 impl Percentiles {
     /// Construct a new empty collection.
-    pub(crate) fn new() -> Self {
+    pub fn new() -> Self {
         Self::default()
     }
 
@@ -132,16 +495,75 @@ impl Percentiles {
         self.buckets.is_empty()
     }
 
-    /// Insert a sample.
-    pub(crate) fn insert(&mut self, p: u32, samples: &[Duration]) {
-        let perc = (p as f32) / 10000f32;
+    /// Get the value of the percentile `p`, expressed in basis `10000`.
+    ///
+    /// Returns `None` if the percentile hasn't been recorded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use lib::cli::Percentiles;
+    ///
+    /// let samples = [10, 20, 30].map(Duration::from_millis);
+    ///
+    /// let mut percentiles = Percentiles::new();
+    /// percentiles.insert(5000, &samples);
+    /// percentiles.insert(9900, &samples);
+    ///
+    /// assert_eq!(percentiles.get(5000), Some(Duration::from_millis(20)));
+    /// assert_eq!(percentiles.get(9500), None);
+    /// ```
+    pub fn get(&self, p: u32) -> Option<Duration> {
+        let (_, value) = self.buckets.iter().find(|(n, _)| *n == p)?;
+        Some(*value)
+    }
 
-        let index = ((samples.len() as f32) * perc) as usize;
-        let value = samples.get(index).or_else(|| samples.last());
+    /// Insert the percentile `p` of the given sorted `samples`, where `p` is
+    /// expressed in basis `10000` so that `9950` is the 99.5th percentile.
+    ///
+    /// The value is linearly interpolated between the two closest samples
+    /// (the `R-7` quantile method). Percentiles outside of `0..=10000` are
+    /// clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use lib::cli::Percentiles;
+    ///
+    /// let samples = [10, 20, 40].map(Duration::from_millis);
+    ///
+    /// let mut percentiles = Percentiles::new();
+    /// percentiles.insert(0, &samples);
+    /// percentiles.insert(2500, &samples);
+    /// percentiles.insert(5000, &samples[..2]);
+    /// percentiles.insert(7500, &samples);
+    /// percentiles.insert(20000, &samples);
+    ///
+    /// let values = percentiles.buckets.iter().map(|(_, d)| d.as_micros()).collect::<Vec<_>>();
+    /// assert_eq!(values, [10000, 15000, 15000, 30000, 40000]);
+    /// ```
+    pub fn insert(&mut self, p: u32, samples: &[Duration]) {
+        let (Some(first), Some(last)) = (samples.first(), samples.last()) else {
+            return;
+        };
 
-        if let Some(value) = value {
-            self.buckets.push((p, *value));
-        }
+        let value = match p {
+            0 => *first,
+            10000.. => *last,
+            _ => {
+                let h = (samples.len() - 1) * p as usize;
+                let (index, fract) = (h / 10000, (h % 10000) as u32);
+
+                match (samples.get(index), samples.get(index + 1)) {
+                    (Some(&lo), Some(&hi)) => lo + (hi - lo) * fract / 10000,
+                    _ => *last,
+                }
+            }
+        };
+
+        self.buckets.push((p, value));
     }
 }
 
@@ -152,6 +574,9 @@ pub struct Report {
     pub max: Option<Duration>,
     pub avg: Duration,
     pub percentiles: Percentiles,
+    /// Number of items processed per iteration.
+    #[serde(default)]
+    pub items: Option<u64>,
 }
 
 impl Report {
@@ -170,11 +595,351 @@ impl Report {
             max,
             avg,
             percentiles,
+            items: None,
+        }
+    }
+
+    /// Construct a report out of unsorted timing `samples`, such as those
+    /// collected by a custom measurement loop.
+    ///
+    /// Each of the given `percentiles` is expressed in basis `10000`, see
+    /// [Percentiles::insert].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use lib::cli::Report;
+    ///
+    /// let samples = [40, 10, 30, 20].map(Duration::from_millis);
+    /// let report = Report::from_samples(&samples, &[5000, 10000]);
+    ///
+    /// assert_eq!(report.count, 4);
+    /// assert_eq!(report.min, Some(Duration::from_millis(10)));
+    /// assert_eq!(report.max, Some(Duration::from_millis(40)));
+    /// assert_eq!(report.avg, Duration::from_millis(25));
+    /// assert_eq!(
+    ///     report.percentiles.buckets,
+    ///     [(5000, Duration::from_millis(25)), (10000, Duration::from_millis(40))]
+    /// );
+    ///
+    /// let report = Report::from_samples(&[], &[5000]);
+    /// assert_eq!(report.count, 0);
+    /// assert_eq!(report.min, None);
+    /// assert!(report.percentiles.buckets.is_empty());
+    /// ```
+    pub fn from_samples(samples: &[Duration], percentiles: &[u32]) -> Self {
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let sum = samples.iter().copied().sum::<Duration>();
+
+        let mut buckets = Percentiles::new();
+
+        for &p in percentiles {
+            buckets.insert(p, &samples);
+        }
+
+        Self::new(
+            samples.len(),
+            samples.first().copied(),
+            samples.last().copied(),
+            sum,
+            buckets,
+        )
+    }
+
+    /// Get the value of the percentile `p`, expressed in basis `10000`.
+    ///
+    /// See [Percentiles::get].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use lib::cli::Report;
+    ///
+    /// let samples = [10, 20, 30, 40].map(Duration::from_millis);
+    /// let report = Report::from_samples(&samples, &[5000, 9900]);
+    ///
+    /// assert!(report.percentile(9900).unwrap() < Duration::from_millis(50));
+    /// assert_eq!(report.percentile(5000), Some(Duration::from_millis(25)));
+    /// assert_eq!(report.percentile(2500), None);
+    /// ```
+    #[inline]
+    pub fn percentile(&self, p: u32) -> Option<Duration> {
+        self.percentiles.get(p)
+    }
+
+    /// Get the number of items processed per second, if the number of items
+    /// per iteration is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use lib::cli::Report;
+    ///
+    /// let report = Report {
+    ///     count: 10,
+    ///     avg: Duration::from_millis(2),
+    ///     items: Some(1000),
+    ///     ..Report::default()
+    /// };
+    ///
+    /// assert_eq!(report.throughput(), Some(500000.0));
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "count: 10, min: ?, max: ?, avg: 2ms, throughput: 500000.00 items/s"
+    /// );
+    /// ```
+    pub fn throughput(&self) -> Option<f64> {
+        let items = self.items?;
+
+        if self.avg.is_zero() {
+            return None;
+        }
+
+        Some(items as f64 / self.avg.as_secs_f64())
+    }
+
+    /// Compare this report against a `baseline`, computing the relative
+    /// change of each timing.
+    ///
+    /// Percentiles are only compared if they are present in both reports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use lib::cli::{Percentiles, Report};
+    ///
+    /// let baseline = Report {
+    ///     min: Some(Duration::from_micros(1000)),
+    ///     max: Some(Duration::from_micros(2000)),
+    ///     avg: Duration::from_micros(1200),
+    ///     percentiles: Percentiles { buckets: vec![(5000, Duration::from_micros(1200))] },
+    ///     ..Report::default()
+    /// };
+    ///
+    /// let report = Report {
+    ///     min: Some(Duration::from_micros(1000)),
+    ///     max: Some(Duration::from_micros(3000)),
+    ///     avg: Duration::from_micros(1100),
+    ///     percentiles: Percentiles { buckets: vec![(5000, Duration::from_micros(900))] },
+    ///     ..Report::default()
+    /// };
+    ///
+    /// let delta = report.delta(&baseline);
+    /// assert_eq!(delta.avg.percent().map(|p| (p * 10.0).round()), Some(-83.0));
+    /// assert_eq!(delta.avg.to_string(), "1.1ms (-8.3%)");
+    /// assert_eq!(
+    ///     delta.to_string(),
+    ///     "min: 1ms (+0.0%), max: 3ms (+50.0%), avg: 1.1ms (-8.3%), 50th: 900µs (-25.0%)"
+    /// );
+    /// ```
+    pub fn delta(&self, baseline: &Report) -> ReportDelta {
+        let change = |value: Option<Duration>, baseline: Option<Duration>| {
+            Some(Change {
+                value: value?,
+                baseline: baseline?,
+            })
+        };
+
+        let mut percentiles = Vec::new();
+
+        for &(p, value) in &self.percentiles.buckets {
+            let Some(baseline) = baseline.percentiles.get(p) else {
+                continue;
+            };
+
+            percentiles.push((p, Change { value, baseline }));
+        }
+
+        ReportDelta {
+            min: change(self.min, baseline.min),
+            max: change(self.max, baseline.max),
+            avg: Change {
+                value: self.avg,
+                baseline: baseline.avg,
+            },
+            percentiles,
+        }
+    }
+}
+
+/// The change of a single timing compared to a baseline.
+#[derive(Debug, Clone, Copy)]
+pub struct Change {
+    /// The current value.
+    pub value: Duration,
+    /// The value of the baseline.
+    pub baseline: Duration,
+}
+
+impl Change {
+    /// Get the change relative to the baseline in percent, where a negative
+    /// value is an improvement.
+    ///
+    /// Returns `None` if the baseline is zero.
+    pub fn percent(&self) -> Option<f64> {
+        if self.baseline.is_zero() {
+            return None;
+        }
+
+        let value = self.value.as_secs_f64();
+        let baseline = self.baseline.as_secs_f64();
+        Some((value - baseline) / baseline * 100.0)
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.percent() {
+            Some(percent) => write!(f, "{:?} ({percent:+.1}%)", self.value),
+            None => write!(f, "{:?} (?)", self.value),
+        }
+    }
+}
+
+/// The difference between two reports, as constructed by [Report::delta].
+pub struct ReportDelta {
+    pub min: Option<Change>,
+    pub max: Option<Change>,
+    pub avg: Change,
+    pub percentiles: Vec<(u32, Change)>,
+}
+
+impl fmt::Display for ReportDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(min) = &self.min {
+            write!(f, "min: {min}, ")?;
+        }
+
+        if let Some(max) = &self.max {
+            write!(f, "max: {max}, ")?;
+        }
+
+        write!(f, "avg: {}", self.avg)?;
+
+        for (n, change) in &self.percentiles {
+            let rest = Rest(*n % 100);
+            write!(f, ", {}{rest}th: {change}", n / 100)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Helper to format the fractional part of a percentile.
+struct Rest(u32);
+
+impl fmt::Display for Rest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 == 0 {
+            return Ok(());
+        }
+
+        let mut n = self.0;
+
+        while n % 10 == 0 {
+            n /= 10;
         }
+
+        write!(f, ".{n}")
     }
 }
 
+impl Report {
+    /// Format the report with every timing in the given `unit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use lib::cli::{Percentiles, Report, TimeUnit};
+    ///
+    /// let report = Report {
+    ///     count: 2,
+    ///     min: Some(Duration::from_micros(1500)),
+    ///     max: Some(Duration::from_millis(2)),
+    ///     avg: Duration::from_micros(1750),
+    ///     percentiles: Percentiles { buckets: vec![(5000, Duration::from_micros(1750))] },
+    ///     ..Report::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     report.display_in(TimeUnit::Ms).to_string(),
+    ///     "count: 2, min: 1.500ms, max: 2.000ms, avg: 1.750ms\n50th: 1.750ms"
+    /// );
+    /// assert_eq!(
+    ///     report.display_in(TimeUnit::Us).to_string(),
+    ///     "count: 2, min: 1500.000µs, max: 2000.000µs, avg: 1750.000µs\n50th: 1750.000µs"
+    /// );
+    /// assert_eq!(
+    ///     report.display_in(TimeUnit::Ns).to_string(),
+    ///     "count: 2, min: 1500000ns, max: 2000000ns, avg: 1750000ns\n50th: 1750000ns"
+    /// );
+    /// ```
+    pub fn display_in(&self, unit: TimeUnit) -> impl fmt::Display + '_ {
+        self.display(false, Some(unit))
+    }
+
+    /// Format the report, optionally with bold labels and a fixed time unit.
+    pub(crate) fn display(&self, bold: bool, unit: Option<TimeUnit>) -> impl fmt::Display + '_ {
+        DisplayReport {
+            report: self,
+            bold,
+            unit,
+        }
+    }
+}
+
+/// Format the report, with percentiles on a separate line if there are any.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use lib::cli::{Percentiles, Report};
+///
+/// let ms = Duration::from_millis;
+///
+/// let mut report = Report {
+///     count: 3,
+///     min: Some(ms(1)),
+///     max: Some(ms(3)),
+///     avg: ms(2),
+///     ..Report::default()
+/// };
+///
+/// assert_eq!(report.to_string(), "count: 3, min: 1ms, max: 3ms, avg: 2ms");
+///
+/// report.percentiles = Percentiles { buckets: vec![(5000, ms(2))] };
+/// assert_eq!(report.to_string(), "count: 3, min: 1ms, max: 3ms, avg: 2ms\n50th: 2ms");
+///
+/// report.percentiles = Percentiles { buckets: vec![(5000, ms(2)), (9950, ms(3)), (10000, ms(3))] };
+/// assert_eq!(
+///     report.to_string(),
+///     "count: 3, min: 1ms, max: 3ms, avg: 2ms\n50th: 2ms, 99.5th: 3ms, 100th: 3ms"
+/// );
+/// ```
 impl fmt::Display for Report {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(false, None).fmt(f)
+    }
+}
+
+/// Helper to format a report, optionally with bold labels and a fixed time
+/// unit.
+struct DisplayReport<'a> {
+    report: &'a Report,
+    bold: bool,
+    unit: Option<TimeUnit>,
+}
+
+impl fmt::Display for DisplayReport<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let Report {
             count,
@@ -182,65 +947,130 @@ impl fmt::Display for Report {
             max,
             avg,
             percentiles,
-        } = self;
+            ..
+        } = self.report;
 
-        let min = Maybe(min);
-        let max = Maybe(max);
+        let t = |value| Time(value, self.unit);
+        let l = |name| Label(name, self.bold);
 
-        writeln!(f, "count: {count}, min: {min}, max: {max}, avg: {avg:?}")?;
+        let min = Maybe(min.map(t));
+        let max = Maybe(max.map(t));
+        let avg = t(*avg);
 
-        let mut it = percentiles.buckets.iter();
-        let last = it.next_back();
+        write!(
+            f,
+            "{}: {count}, {}: {min}, {}: {max}, {}: {avg}",
+            l("count"),
+            l("min"),
+            l("max"),
+            l("avg")
+        )?;
 
-        for (n, value) in it {
-            let rest = Rest(*n % 100);
-            write!(f, "{}{rest}th: {:?}, ", n / 100, value)?;
+        if let Some(throughput) = self.report.throughput() {
+            write!(f, ", {}: {throughput:.2} items/s", l("throughput"))?;
         }
 
-        if let Some((n, value)) = last {
-            let rest = Rest(*n % 100);
-            write!(f, "{}{rest}th: {:?}", n / 100, value)?;
+        // Percentiles go on their own line, if there are any.
+        for (i, (n, value)) in percentiles.buckets.iter().enumerate() {
+            let sep = if i == 0 { "\n" } else { ", " };
+            write!(f, "{sep}{}: {}", Label(Nth(*n), self.bold), t(*value))?;
         }
 
         return Ok(());
 
-        struct Rest(u32);
+        struct Maybe<T>(Option<T>);
 
-        impl fmt::Display for Rest {
+        impl<T> fmt::Display for Maybe<T>
+        where
+            T: fmt::Display,
+        {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                if self.0 == 0 {
-                    return Ok(());
+                if let Some(value) = &self.0 {
+                    value.fmt(f)
+                } else {
+                    write!(f, "?")
                 }
+            }
+        }
+
+        struct Time(Duration, Option<TimeUnit>);
 
-                let mut n = self.0;
+        impl fmt::Display for Time {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let Time(value, unit) = *self;
 
-                while n % 10 == 0 {
-                    n /= 10;
+                match unit {
+                    None => write!(f, "{value:?}"),
+                    Some(TimeUnit::Ms) => write!(f, "{:.3}ms", value.as_secs_f64() * 1e3),
+                    Some(TimeUnit::Us) => write!(f, "{:.3}µs", value.as_secs_f64() * 1e6),
+                    Some(TimeUnit::Ns) => write!(f, "{}ns", value.as_nanos()),
                 }
+            }
+        }
+
+        struct Nth(u32);
 
-                write!(f, ".{n}")
+        impl fmt::Display for Nth {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}{}th", self.0 / 100, Rest(self.0 % 100))
             }
         }
 
-        struct Maybe<'a, T>(&'a Option<T>);
+        struct Label<T>(T, bool);
 
-        impl<T> fmt::Display for Maybe<'_, T>
+        impl<T> fmt::Display for Label<T>
         where
-            T: fmt::Debug,
+            T: fmt::Display,
         {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                if let Some(value) = self.0 {
-                    value.fmt(f)
+                if self.1 {
+                    write!(f, "\x1b[1m{}\x1b[0m", self.0)
                 } else {
-                    write!(f, "?")
+                    self.0.fmt(f)
                 }
             }
         }
     }
 }
 
+/// Add the timings of another report to this one, as used to total up the
+/// reports of several solutions.
+///
+/// The totals only have an item count if every added report has one.
+///
+/// # Examples
+///
+/// ```
+/// use lib::cli::Report;
+///
+/// let with = |items| Report { count: 1, items, ..Report::default() };
+///
+/// let mut total = Report::default();
+/// total += &with(Some(10));
+/// total += &with(Some(20));
+/// assert_eq!(total.count, 2);
+/// assert_eq!(total.items, Some(30));
+///
+/// total += &with(None);
+/// total += &with(Some(40));
+/// assert_eq!(total.count, 4);
+/// assert_eq!(total.items, None);
+///
+/// let mut total = Report::default();
+/// total += &with(None);
+/// total += &with(Some(10));
+/// assert_eq!(total.items, None);
+/// ```
 impl AddAssign<&Report> for Report {
     fn add_assign(&mut self, rhs: &Report) {
+        // An empty report takes on the item count of the first report added
+        // to it.
+        self.items = if self.count == 0 {
+            rhs.items
+        } else {
+            self.items.zip(rhs.items).map(|(a, b)| a + b)
+        };
+
         self.count += rhs.count;
         self.min = self.min.and_then(|d| Some(d + rhs.min?)).or(rhs.min);
         self.max = self.max.and_then(|d| Some(d + rhs.max?)).or(rhs.max);
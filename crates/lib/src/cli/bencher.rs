@@ -1,12 +1,15 @@
 use core::fmt;
 use std::{
-    io::Write,
+    fs::File,
+    io::{BufReader, Write},
+    path::{Path, PathBuf},
     time::{Duration, Instant},
 };
 
 use anyhow::{bail, Context, Error, Result};
+use serde::Deserialize;
 
-use crate::cli::{Opts, Output, OutputEq, OutputKind, Report};
+use crate::cli::{Opts, Output, OutputEq, OutputKind, Report, TimeUnit};
 
 use super::Percentiles;
 
@@ -19,9 +22,39 @@ const DEFAULT_TIME_LIMIT: u64 = 400;
 /// At 10 microsecond runtime we need to adjust our timing method.
 const THRESHOLD: u32 = 10_000_000;
 
+/// The maximum number of inputs to set up ahead of timing them.
+const MAX_BATCH: usize = 1024;
+
+/// Benchmark a solution according to the options it was started with.
+///
+/// # Examples
+///
+/// Writing the report to a file with `--output`:
+///
+/// ```
+/// use std::fs::File;
+/// use lib::cli::{Bencher, Opts, Report};
+///
+/// let path = std::env::temp_dir().join("lib-bencher-output.json");
+/// let args = ["--bench", "--iter", "4", "--warmup", "0", "--output"];
+/// let opts = Opts::from_args(args.into_iter().map(Into::into).chain([path.clone().into()]))?;
+///
+/// Bencher::new(&opts).run(Some(42u32), || Ok::<_, anyhow::Error>(42u32))?;
+///
+/// let report: Report = serde_json::from_reader(File::open(&path)?)?;
+/// assert_eq!(report.count, 4);
+/// assert!(report.min <= report.max);
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<_, anyhow::Error>(())
+/// ```
 pub struct Bencher {
     iter: Option<usize>,
+    min_samples: usize,
+    items: Option<u64>,
+    baseline: Option<PathBuf>,
+    output: Option<PathBuf>,
     kind: OutputKind,
+    time_unit: Option<TimeUnit>,
     warmup: Duration,
     time_limit: Duration,
 }
@@ -34,49 +67,174 @@ impl Bencher {
         let time_limit = Duration::from_millis(opts.time_limit.unwrap_or(DEFAULT_TIME_LIMIT));
 
         Self {
-            iter: opts.iter,
-            kind: if opts.json {
-                OutputKind::Json
-            } else {
-                OutputKind::Normal
-            },
+            iter: opts.iter(),
+            min_samples: opts.min_samples.unwrap_or_default(),
+            items: opts.items,
+            baseline: opts.baseline.clone(),
+            output: opts.output.clone(),
+            kind: opts.output_kind(),
+            time_unit: opts.time_unit,
             warmup,
             time_limit,
         }
     }
 
     /// Bench the given fn.
-    pub fn run<T, O, C, E>(&self, expected: Option<C>, iter: T) -> Result<()>
+    ///
+    /// # Examples
+    ///
+    /// With `--warmup 0` the solution is only run once to check the answer
+    /// before the timed iterations:
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use std::fs::File;
+    /// use lib::cli::{Bencher, Opts, Report};
+    ///
+    /// let path = std::env::temp_dir().join("lib-bencher-no-warmup.json");
+    /// let args = ["--bench", "--iter", "3", "--warmup", "0", "--output"];
+    /// let opts = Opts::from_args(args.into_iter().map(Into::into).chain([path.clone().into()]))?;
+    ///
+    /// let calls = Cell::new(0);
+    ///
+    /// Bencher::new(&opts).run(Some(42u32), || {
+    ///     calls.set(calls.get() + 1);
+    ///     Ok::<_, anyhow::Error>(42u32)
+    /// })?;
+    ///
+    /// let report: Report = serde_json::from_reader(File::open(&path)?)?;
+    /// assert_eq!(report.count, 3);
+    /// assert_eq!(calls.get(), 4);
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    ///
+    /// With `--min-samples` at least that many samples are taken even if the
+    /// time limit is reached first. Here each call is slow enough to be timed
+    /// as its own sample:
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use std::fs::File;
+    /// use lib::cli::{Bencher, Opts, Report};
+    ///
+    /// let path = std::env::temp_dir().join("lib-bencher-min-samples.json");
+    /// let args = ["--bench", "--warmup", "0", "--time-limit", "1", "--min-samples", "5"];
+    /// let args = args.into_iter().map(Into::into);
+    /// let opts = Opts::from_args(args.chain(["--output".into(), path.clone().into()]))?;
+    ///
+    /// Bencher::new(&opts).run(Some(42u32), || {
+    ///     std::thread::sleep(Duration::from_millis(11));
+    ///     Ok::<_, anyhow::Error>(42u32)
+    /// })?;
+    ///
+    /// let report: Report = serde_json::from_reader(File::open(&path)?)?;
+    /// assert_eq!(report.count, 5);
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn run<T, O, C, E>(&self, expected: Option<C>, mut iter: T) -> Result<()>
     where
         T: FnMut() -> Result<O, E>,
         O: fmt::Debug + OutputEq<C>,
         C: fmt::Debug,
         Error: From<E>,
     {
-        let stdout = std::io::stdout();
-        let mut o = Output::new(stdout.lock(), self.kind);
+        self.iter_batched(expected, || (), |()| iter())
+    }
+
+    /// Bench the given `routine`, where each call is handed fresh state
+    /// constructed by `setup`.
+    ///
+    /// Calls to `setup` are not included in the timed samples, which is
+    /// useful for solutions that mutate expensive owned input. Fast routines
+    /// are run many times per sample, but at most 1024 inputs are set up
+    /// ahead of time, which bounds the memory used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use std::fs::File;
+    /// use lib::cli::{Bencher, Opts, Report};
+    ///
+    /// let path = std::env::temp_dir().join("lib-bencher-iter-batched.json");
+    /// let args = ["--bench", "--iter", "3", "--warmup", "0", "--output"];
+    /// let opts = Opts::from_args(args.into_iter().map(Into::into).chain([path.clone().into()]))?;
+    ///
+    /// let setup = || {
+    ///     std::thread::sleep(Duration::from_millis(50));
+    ///     vec![3u32, 1, 2]
+    /// };
+    ///
+    /// let routine = |mut values: Vec<u32>| {
+    ///     values.sort();
+    ///     Ok::<_, anyhow::Error>(values[0])
+    /// };
+    ///
+    /// Bencher::new(&opts).iter_batched(Some(1u32), setup, routine)?;
+    ///
+    /// let report: Report = serde_json::from_reader(File::open(&path)?)?;
+    /// assert_eq!(report.count, 3);
+    /// assert!(report.max.unwrap() < Duration::from_millis(50));
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn iter_batched<S, R, O, C, E>(
+        &self,
+        expected: Option<C>,
+        setup: impl FnMut() -> S,
+        routine: R,
+    ) -> Result<()>
+    where
+        R: FnMut(S) -> Result<O, E>,
+        O: fmt::Debug + OutputEq<C>,
+        C: fmt::Debug,
+        Error: From<E>,
+    {
+        let reports = match &self.output {
+            Some(path) => Some(
+                File::create(path)
+                    .with_context(|| format!("{}: failed to create output", path.display()))?,
+            ),
+            None => None,
+        };
 
-        if let Err(e) = self.inner_run(&mut o, expected, iter) {
-            o.error(e)?;
+        let stdout = std::io::stdout();
+        let mut o = Output::new(stdout.lock(), self.kind)
+            .with_reports(reports)
+            .with_time_unit(self.time_unit);
+
+        match self.inner_run(&mut o, expected, setup, routine) {
+            Ok(()) => {
+                if let Some(path) = &self.output {
+                    o.info(format_args!("wrote report to {}", path.display()))?;
+                }
+            }
+            Err(e) => {
+                o.error(e)?;
+            }
         }
 
         Ok(())
     }
 
-    fn inner_run<T, O, C, E>(
+    fn inner_run<S, R, O, C, E>(
         &self,
         o: &mut Output<impl Write>,
         expected: Option<C>,
-        mut f: T,
+        mut setup: impl FnMut() -> S,
+        mut f: R,
     ) -> Result<()>
     where
-        T: FnMut() -> Result<O, E>,
+        R: FnMut(S) -> Result<O, E>,
         O: fmt::Debug + OutputEq<C>,
         C: fmt::Debug,
         Error: From<E>,
     {
+        let input = setup();
         let before = Instant::now();
-        let value = f()?;
+        let value = f(input)?;
 
         // run once to check against expected.
         if let Some(expect) = &expected {
@@ -87,16 +245,20 @@ impl Bencher {
 
         let _ = black_box(value);
 
-        let iter = match self.iter {
-            Some(iter) => iter,
+        // With an explicit number of iterations each one is timed as its own
+        // sample and the time limit is ignored.
+        let (iter, count) = match self.iter {
+            Some(count) => (1, Some(count)),
             None => {
                 let duration = before.elapsed();
 
-                if duration.as_secs() == 0 && duration.subsec_nanos() <= THRESHOLD {
+                let iter = if duration.as_secs() == 0 && duration.subsec_nanos() <= THRESHOLD {
                     (THRESHOLD / duration.subsec_nanos()) as usize
                 } else {
                     1
-                }
+                };
+
+                (iter, None)
             }
         };
 
@@ -106,7 +268,7 @@ impl Bencher {
             o.info(format_args!("warming up ({:?})...", self.warmup))?;
 
             loop {
-                black_box(f()?);
+                black_box(f(setup())?);
 
                 if start.elapsed() >= self.warmup {
                     break;
@@ -116,22 +278,45 @@ impl Bencher {
 
         let mut samples = Vec::new();
 
-        o.info(format_args!("running benches ({:?})...", self.time_limit))?;
+        if let Some(count) = count {
+            o.info(format_args!("running benches ({count} iterations)..."))?;
 
-        let start = Instant::now();
+            for _ in 0..count {
+                let input = setup();
+                let before = Instant::now();
+                black_box(f(input)?);
+                samples.push(before.elapsed());
+            }
+        } else {
+            o.info(format_args!("running benches ({:?})...", self.time_limit))?;
 
-        loop {
-            let before = Instant::now();
+            let start = Instant::now();
+            let mut inputs = Vec::with_capacity(iter.min(MAX_BATCH));
 
-            for _ in 0..iter {
-                black_box(f()?);
-            }
+            loop {
+                let mut sample = Duration::ZERO;
+                let mut remaining = iter;
+
+                // Set up and time the inputs in batches, so that we don't
+                // hold on to more than `MAX_BATCH` of them at once.
+                while remaining > 0 {
+                    let batch = remaining.min(MAX_BATCH);
+                    inputs.extend((0..batch).map(|_| setup()));
+                    let before = Instant::now();
+
+                    for input in inputs.drain(..) {
+                        black_box(f(input)?);
+                    }
+
+                    sample += before.elapsed();
+                    remaining -= batch;
+                }
 
-            let now = Instant::now();
-            samples.push(now.duration_since(before));
+                samples.push(sample);
 
-            if now.duration_since(start) >= self.time_limit {
-                break;
+                if start.elapsed() >= self.time_limit && samples.len() >= self.min_samples {
+                    break;
+                }
             }
         }
 
@@ -152,12 +337,40 @@ impl Bencher {
         let min = samples.first().copied();
         let max = samples.last().copied();
 
-        let report = Report::new(samples.len() * iter, min, max, sum, percentiles);
+        let mut report = Report::new(samples.len() * iter, min, max, sum, percentiles);
+        report.items = self.items;
         o.report(&report)?;
+
+        if let Some(path) = &self.baseline {
+            let baseline = load_baseline(path)
+                .with_context(|| format!("{}: failed to load baseline", path.display()))?;
+            o.info(format_args!("baseline: {}", report.delta(&baseline)))?;
+        }
+
         Ok(())
     }
 }
 
+/// Load a baseline report, either saved directly or as part of the output of
+/// `--json`.
+fn load_baseline(path: &Path) -> Result<Report> {
+    let file = BufReader::new(File::open(path)?);
+
+    for value in serde_json::Deserializer::from_reader(file).into_iter() {
+        let value: serde_json::Value = value?;
+
+        let value = match value.get("type").and_then(|ty| ty.as_str()) {
+            Some("report") => value.get("data").context("missing report data")?,
+            Some(..) => continue,
+            None => &value,
+        };
+
+        return Ok(Report::deserialize(value)?);
+    }
+
+    bail!("no report")
+}
+
 /// A function that is opaque to the optimizer, used to prevent the compiler from
 /// optimizing away computations in a benchmark.
 ///
@@ -0,0 +1,67 @@
+use core::fmt;
+use std::process::ExitCode;
+
+use anyhow::Result;
+
+use crate::cli::{Opts, Output, OutputEq, OutputKind};
+
+/// Check the answer of a solution against its expected value, as used by
+/// `--check`.
+///
+/// # Examples
+///
+/// ```
+/// use std::process::ExitCode;
+/// use lib::cli::{Checker, Opts};
+///
+/// let opts = Opts::from_args(["--check"].map(Into::into))?;
+/// let checker = Checker::new(&opts);
+///
+/// assert_eq!(checker.check(Some(42u32), 42u32)?, ExitCode::SUCCESS);
+/// assert_eq!(checker.check(Some(42u32), 43u32)?, ExitCode::FAILURE);
+/// assert_eq!(checker.check(None::<u32>, 42u32)?, ExitCode::FAILURE);
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub struct Checker {
+    kind: OutputKind,
+}
+
+impl Checker {
+    /// Construct a new checker.
+    #[inline]
+    pub fn new(opts: &Opts) -> Self {
+        Self {
+            kind: opts.output_kind(),
+        }
+    }
+
+    /// Compare `value` to `expected`, printing `OK` or `MISMATCH` and
+    /// returning the exit code the process should use.
+    ///
+    /// Not having an expected value counts as a mismatch.
+    pub fn check<O, C>(&self, expected: Option<C>, value: O) -> Result<ExitCode>
+    where
+        O: fmt::Debug + OutputEq<C>,
+        C: fmt::Debug,
+    {
+        let stdout = std::io::stdout();
+        let mut o = Output::new(stdout.lock(), self.kind);
+
+        let Some(expected) = expected else {
+            o.error(format_args!(
+                "MISMATCH: {value:?} (value), but there's no expected value"
+            ))?;
+            return Ok(ExitCode::FAILURE);
+        };
+
+        if !value.output_eq(&expected) {
+            o.error(format_args!(
+                "MISMATCH: {value:?} (value) != {expected:?} (expected)"
+            ))?;
+            return Ok(ExitCode::FAILURE);
+        }
+
+        o.info(format_args!("OK: {value:?}"))?;
+        Ok(ExitCode::SUCCESS)
+    }
+}
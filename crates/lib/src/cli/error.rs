@@ -5,6 +5,31 @@ use crate::env::Size;
 use crate::input::{IStr, IStrError};
 
 /// Used in macros to associate context with an error.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+/// use lib::cli::error_context;
+///
+/// let data = IStr::new(b"ab\ncd=x", Size::ZERO);
+/// let mut input = data;
+/// input.advance(5);
+/// let error = input.expect("->").unwrap_err();
+/// assert_eq!(error_context("d01.txt", data, error).to_string(), "d01.txt:2:3");
+///
+/// let data = IStr::new(b"1\n300\n", Size::ZERO);
+/// let mut input = data;
+/// let error = input.next::<(u8, u8)>().unwrap_err();
+/// assert_eq!(error_context("d01.txt", data, error).to_string(), "d01.txt:2:1-3");
+///
+/// let data = IStr::new(b"abc", Size::ZERO);
+/// let mut input = data;
+/// input.advance(2);
+/// let error = input.expect("d").unwrap_err();
+/// assert_eq!(error_context("d01.txt", data, error).to_string(), "d01.txt:1:3");
+/// ```
 #[doc(hidden)]
 pub fn error_context<E>(path: &'static str, data: IStr, error: E) -> anyhow::Error
 where
@@ -19,25 +44,37 @@ where
     error.context(cli_error)
 }
 
-/// A line and column combination.
+/// A line and column span.
+///
+/// All fields are zero-based, with `end` being exclusive. It is displayed as
+/// one-based `line:start`, or `line:start-end` with an inclusive `end` column
+/// if the span covers more than one column.
 #[derive(Default, Debug, Clone, Copy)]
 pub struct LineCol {
     line: usize,
     start: usize,
+    end: usize,
 }
 
 impl LineCol {
-    pub(crate) const EMPTY: Self = Self::new(0, 0);
+    #[cfg(prod)]
+    pub(crate) const EMPTY: Self = Self::new(0, 0, 0);
 
-    pub(crate) const fn new(line: usize, start: usize) -> Self {
-        Self { line, start }
+    pub(crate) const fn new(line: usize, start: usize, end: usize) -> Self {
+        Self { line, start, end }
     }
 }
 
 impl fmt::Display for LineCol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let line = self.line + 1;
-        write!(f, "{line}:{}", self.start)
+        let start = self.start + 1;
+
+        if self.end > start {
+            write!(f, "{line}:{start}-{}", self.end)
+        } else {
+            write!(f, "{line}:{start}")
+        }
     }
 }
 
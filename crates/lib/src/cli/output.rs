@@ -1,19 +1,33 @@
 use core::fmt;
+use core::time::Duration;
+use std::fs::File;
 use std::io::{self, Write};
+use std::time::Instant;
 
 use serde::Serialize;
 
-use crate::cli::Report;
+use crate::cli::{Report, TimeUnit};
 
 pub(crate) struct Output<O> {
     out: O,
     kind: OutputKind,
+    /// File to write JSON reports to instead of `out`.
+    reports: Option<File>,
+    /// When progress was last written.
+    last_progress: Option<Instant>,
+    /// Fixed unit to print report timings in.
+    time_unit: Option<TimeUnit>,
 }
 
+/// Minimum interval between progress updates.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum OutputKind {
     Json,
     Normal,
+    /// Normal output colored with ANSI escape codes.
+    Color,
 }
 
 impl<O> Output<O>
@@ -21,7 +35,23 @@ where
     O: Write,
 {
     pub(crate) fn new(out: O, kind: OutputKind) -> Self {
-        Self { out, kind }
+        Self {
+            out,
+            kind,
+            reports: None,
+            last_progress: None,
+            time_unit: None,
+        }
+    }
+
+    /// Write reports as JSON to the given file instead.
+    pub(crate) fn with_reports(self, reports: Option<File>) -> Self {
+        Self { reports, ..self }
+    }
+
+    /// Print report timings in the given unit instead of scaling them.
+    pub(crate) fn with_time_unit(self, time_unit: Option<TimeUnit>) -> Self {
+        Self { time_unit, ..self }
     }
 
     pub(crate) fn info(&mut self, m: impl fmt::Display) -> io::Result<()> {
@@ -33,6 +63,12 @@ where
     }
 
     pub(crate) fn report(&mut self, report: &Report) -> io::Result<()> {
+        if let Some(file) = &mut self.reports {
+            serde_json::to_writer(&mut *file, report)?;
+            writeln!(file)?;
+            return Ok(());
+        }
+
         match &self.kind {
             OutputKind::Json => {
                 self.json(&Line {
@@ -41,13 +77,52 @@ where
                 })?;
             }
             OutputKind::Normal => {
-                writeln!(self.out, "{report}")?;
+                writeln!(self.out, "{}", report.display(false, self.time_unit))?;
+            }
+            OutputKind::Color => {
+                writeln!(self.out, "{}", report.display(true, self.time_unit))?;
             }
         }
 
         Ok(())
     }
 
+    /// Write a progress line, which is updated in place.
+    ///
+    /// Updates are throttled to [PROGRESS_INTERVAL] except for the final
+    /// one, and nothing is written in JSON mode. Returns `true` if an update
+    /// was written.
+    pub(crate) fn progress(&mut self, done: u64, total: u64, now: Instant) -> io::Result<bool> {
+        if matches!(self.kind, OutputKind::Json) {
+            return Ok(false);
+        }
+
+        let finished = done >= total;
+
+        if let Some(last) = self.last_progress {
+            if !finished && now.saturating_duration_since(last) < PROGRESS_INTERVAL {
+                return Ok(false);
+            }
+        }
+
+        self.last_progress = Some(now);
+
+        let percent = if total == 0 {
+            100.0
+        } else {
+            done as f64 / total as f64 * 100.0
+        };
+
+        write!(self.out, "\rprogress: {done}/{total} ({percent:.1}%)")?;
+
+        if finished {
+            writeln!(self.out)?;
+        }
+
+        self.out.flush()?;
+        Ok(true)
+    }
+
     fn message(&mut self, kind: MessageKind, m: impl fmt::Display) -> io::Result<()> {
         match &self.kind {
             OutputKind::Json => {
@@ -59,6 +134,14 @@ where
             OutputKind::Normal => {
                 writeln!(self.out, "{kind}: {m}")?;
             }
+            OutputKind::Color => {
+                let color = match kind {
+                    MessageKind::Info => "32",
+                    MessageKind::Error => "31",
+                };
+
+                writeln!(self.out, "\x1b[{color}m{kind}\x1b[0m: {m}")?;
+            }
         }
 
         Ok(())
@@ -1,6 +1,29 @@
-use arrayvec::ArrayString;
+use core::hash::{BuildHasher, Hash};
+use std::collections::{HashMap, HashSet};
+
+use arrayvec::{ArrayString, ArrayVec};
+use bstr::BStr;
 
 /// Helper trait to more conveniently test things for equality.
+///
+/// Outputs which can be viewed as bytes, like byte vectors, strings and byte
+/// strings, compare equal to each other if their bytes are equal.
+///
+/// # Examples
+///
+/// ```
+/// use lib::cli::OutputEq;
+/// use lib::prelude::*;
+///
+/// let mut output = ArrayVec::<u8, 16>::new();
+/// output.extend(*b"RFFFWBPNS");
+///
+/// assert!(output.output_eq(&b"RFFFWBPNS"));
+/// assert!(!output.output_eq(&b"RFFFWBPNX"));
+/// assert!(!output.output_eq(&b"RFFFWBPN"));
+/// assert!(BStr::new("ab").output_eq(&b"ab"));
+/// assert!(ArrayString::<4>::from("ab").unwrap().output_eq(&&b"ab"[..]));
+/// ```
 pub trait OutputEq<O = Self>
 where
     O: ?Sized,
@@ -33,6 +56,69 @@ where
     }
 }
 
+/// Compare sets regardless of the order in which elements were inserted.
+///
+/// Since the element types might differ, every element is compared against
+/// every other so this is only intended for small answers.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use lib::cli::OutputEq;
+///
+/// let a = HashSet::from([1u32, 2, 3]);
+/// let b = HashSet::from([3u32, 1, 2]);
+/// assert!(a.output_eq(&b));
+///
+/// let c = HashSet::from([1u32, 2, 4]);
+/// assert!(!a.output_eq(&c));
+/// assert!(!a.output_eq(&HashSet::from([1u32, 2])));
+/// ```
+impl<A, B, S, T> OutputEq<HashSet<B, T>> for HashSet<A, S>
+where
+    A: OutputEq<B>,
+{
+    #[inline]
+    fn output_eq(&self, other: &HashSet<B, T>) -> bool {
+        self.len() == other.len() && self.iter().all(|a| other.iter().any(|b| a.output_eq(b)))
+    }
+}
+
+/// Compare maps with equal keys regardless of the order in which entries were
+/// inserted.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use lib::cli::OutputEq;
+///
+/// let a = HashMap::from([("a", 1u32), ("b", 2)]);
+/// let b = HashMap::from([("b", 2u32), ("a", 1)]);
+/// assert!(a.output_eq(&b));
+///
+/// let c = HashMap::from([("a", 1u32), ("b", 3)]);
+/// assert!(!a.output_eq(&c));
+/// assert!(!a.output_eq(&HashMap::from([("a", 1u32)])));
+/// ```
+impl<K, A, B, S, T> OutputEq<HashMap<K, B, T>> for HashMap<K, A, S>
+where
+    K: Eq + Hash,
+    A: OutputEq<B>,
+    T: BuildHasher,
+{
+    #[inline]
+    fn output_eq(&self, other: &HashMap<K, B, T>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, a)| other.get(key).is_some_and(|b| a.output_eq(b)))
+    }
+}
+
 impl<const N: usize> OutputEq<ArrayString<N>> for &str {
     #[inline]
     fn output_eq(&self, other: &ArrayString<N>) -> bool {
@@ -47,6 +133,72 @@ impl<const N: usize> OutputEq<&str> for ArrayString<N> {
     }
 }
 
+/// Compare outputs which can be viewed as bytes.
+macro_rules! bytes_eq {
+    ($([$($g:tt)*] $a:ty => $b:ty),* $(,)?) => {
+        $(
+            impl<$($g)*> OutputEq<$b> for $a {
+                #[inline]
+                fn output_eq(&self, other: &$b) -> bool {
+                    self.as_bytes() == other.as_bytes()
+                }
+            }
+        )*
+    };
+}
+
+/// Helper to view an output as bytes.
+trait AsBytes {
+    fn as_bytes(&self) -> &[u8];
+}
+
+impl AsBytes for &[u8] {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<const N: usize> AsBytes for &[u8; N] {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        &self[..]
+    }
+}
+
+impl AsBytes for &BStr {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<const N: usize> AsBytes for ArrayString<N> {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl<const N: usize> AsBytes for ArrayVec<u8, N> {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+bytes_eq! {
+    ['a, 'b] &'a [u8] => &'b [u8],
+    ['a, const M: usize] &'a [u8] => &'static [u8; M],
+    ['a, 'b] &'a BStr => &'b BStr,
+    ['a, const M: usize] &'a BStr => &'static [u8; M],
+    ['a, const N: usize] &'a [u8] => ArrayString<N>,
+    ['a, const N: usize] ArrayString<N> => &'a [u8],
+    [const N: usize, const M: usize] ArrayString<N> => &'static [u8; M],
+    ['a, const N: usize] ArrayVec<u8, N> => &'a [u8],
+    [const N: usize, const M: usize] ArrayVec<u8, N> => &'static [u8; M],
+}
+
 macro_rules! partial_eq {
     ($ty:ty) => {
         impl OutputEq<$ty> for $ty {
@@ -72,3 +224,61 @@ partial_eq!(i64);
 partial_eq!(i128);
 partial_eq!(bool);
 partial_eq!(());
+
+/// Compare a floating point output against an expected value using an
+/// explicit tolerance.
+///
+/// The first field is the expected value and the second is the tolerance,
+/// which is applied both as an absolute and as a relative bound.
+///
+/// # Examples
+///
+/// ```
+/// use lib::cli::{Approx, OutputEq};
+///
+/// assert!((0.1f64 + 0.2).output_eq(&0.3));
+/// assert!((0.1f64 + 0.2).output_eq(&Approx(0.3, 1e-12)));
+/// assert!(100.5f64.output_eq(&Approx(100.0, 1e-2)));
+/// assert!(!1.5f64.output_eq(&Approx(1.0, 1e-2)));
+/// assert!(!1.5f64.output_eq(&1.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Approx<T>(pub T, pub T);
+
+macro_rules! float_eq {
+    ($ty:ty, $epsilon:expr) => {
+        impl OutputEq<$ty> for $ty {
+            #[inline]
+            fn output_eq(&self, other: &Self) -> bool {
+                approx_eq(*self as f64, *other as f64, $epsilon)
+            }
+        }
+
+        impl OutputEq<Approx<$ty>> for $ty {
+            #[inline]
+            fn output_eq(&self, other: &Approx<$ty>) -> bool {
+                approx_eq(*self as f64, other.0 as f64, other.1 as f64)
+            }
+        }
+    };
+}
+
+float_eq!(f32, F32_EPSILON);
+float_eq!(f64, F64_EPSILON);
+
+/// Default tolerance used when comparing `f32` outputs.
+pub const F32_EPSILON: f64 = 1e-6;
+
+/// Default tolerance used when comparing `f64` outputs.
+pub const F64_EPSILON: f64 = 1e-9;
+
+/// Test if `a` and `b` are within `epsilon` of each other, either absolutely
+/// or relative to the largest magnitude of the two.
+fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    if a == b {
+        return true;
+    }
+
+    let diff = (a - b).abs();
+    diff <= epsilon || diff <= epsilon * a.abs().max(b.abs())
+}
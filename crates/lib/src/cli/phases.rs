@@ -0,0 +1,118 @@
+use core::fmt;
+use core::time::Duration;
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use anyhow::Result;
+
+use crate::cli::{Output, OutputKind};
+
+/// Set when `--profile` has been passed, to the kind of output to use.
+pub(crate) static PROFILE: OnceLock<OutputKind> = OnceLock::new();
+
+/// A lightweight timer for the phases of a solution.
+///
+/// The breakdown is only printed by [Phases::finish] if `--profile` has been
+/// specified.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use std::time::Instant;
+/// use lib::cli::Phases;
+///
+/// let start = Instant::now();
+///
+/// let mut phases = Phases::with_start(start);
+/// phases.mark_at("parse", start + Duration::from_millis(2));
+/// phases.mark_at("part1", start + Duration::from_millis(7));
+/// phases.mark_at("part2", start + Duration::from_millis(10));
+///
+/// assert_eq!(
+///     phases.phases(),
+///     &[
+///         ("parse", Duration::from_millis(2)),
+///         ("part1", Duration::from_millis(5)),
+///         ("part2", Duration::from_millis(3)),
+///     ]
+/// );
+///
+/// assert_eq!(phases.total(), Duration::from_millis(10));
+/// assert_eq!(phases.to_string(), "parse: 2ms, part1: 5ms, part2: 3ms, total: 10ms");
+/// ```
+pub struct Phases {
+    last: Instant,
+    phases: Vec<(&'static str, Duration)>,
+}
+
+impl Phases {
+    /// Construct a new phase timer starting now.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_start(Instant::now())
+    }
+
+    /// Construct a new phase timer starting at the given instant.
+    #[inline]
+    pub fn with_start(start: Instant) -> Self {
+        Self {
+            last: start,
+            phases: Vec::new(),
+        }
+    }
+
+    /// Mark the end of the phase with the given `name`.
+    #[inline]
+    pub fn mark(&mut self, name: &'static str) {
+        self.mark_at(name, Instant::now());
+    }
+
+    /// Mark the end of the phase with the given `name` at the given instant.
+    pub fn mark_at(&mut self, name: &'static str, now: Instant) {
+        let duration = now.saturating_duration_since(self.last);
+        self.last = now;
+        self.phases.push((name, duration));
+    }
+
+    /// Access the duration of each phase marked so far.
+    #[inline]
+    pub fn phases(&self) -> &[(&'static str, Duration)] {
+        &self.phases
+    }
+
+    /// Get the total duration of all phases.
+    #[inline]
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|(_, d)| *d).sum()
+    }
+
+    /// Print the breakdown of phases if `--profile` is enabled.
+    pub fn finish(self) -> Result<()> {
+        let Some(kind) = PROFILE.get() else {
+            return Ok(());
+        };
+
+        let stdout = std::io::stdout();
+        let mut o = Output::new(stdout.lock(), *kind);
+        o.info(format_args!("profile: {self}"))?;
+        Ok(())
+    }
+}
+
+impl Default for Phases {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Phases {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, duration) in &self.phases {
+            write!(f, "{name}: {duration:?}, ")?;
+        }
+
+        write!(f, "total: {:?}", self.total())
+    }
+}
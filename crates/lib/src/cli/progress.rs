@@ -0,0 +1,88 @@
+use std::io;
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use crate::cli::{Output, OutputKind};
+
+/// Set by [Opts::parse][crate::cli::Opts::parse] when not benchmarking, to the
+/// kind of output to use.
+pub(crate) static PROGRESS: OnceLock<OutputKind> = OnceLock::new();
+
+/// A handle for reporting the progress of long-running solutions to stderr.
+///
+/// Solutions construct it through [Progress::new], which is only enabled
+/// when running in the default mode. It can also be constructed from options
+/// through [Opts::progress][crate::cli::Opts::progress].
+///
+/// Updates are throttled to about 10 per second and are silently dropped
+/// when `--json` is used.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use std::time::Instant;
+/// use lib::cli::{Opts, Progress};
+///
+/// let opts = Opts::from_args([])?;
+/// let mut progress = opts.progress();
+///
+/// let start = Instant::now();
+/// assert!(progress.update_at(1, 4, start));
+/// assert!(!progress.update_at(2, 4, start + Duration::from_millis(50)));
+/// assert!(progress.update_at(3, 4, start + Duration::from_millis(100)));
+/// // The final update is always written.
+/// assert!(progress.update_at(4, 4, start + Duration::from_millis(101)));
+///
+/// let opts = Opts::from_args(["--json".into()])?;
+/// assert!(!opts.progress().update_at(1, 4, start));
+///
+/// // Options haven't been parsed, so this is disabled.
+/// assert!(!Progress::new().update_at(1, 4, start));
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub struct Progress {
+    output: Option<Output<io::Stderr>>,
+}
+
+impl Progress {
+    /// Construct a progress handle for the current solution.
+    ///
+    /// This does nothing unless the solution is run in the default mode.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_kind(PROGRESS.get().copied())
+    }
+
+    pub(crate) fn with_kind(kind: Option<OutputKind>) -> Self {
+        Self {
+            output: kind.map(|kind| Output::new(io::stderr(), kind)),
+        }
+    }
+
+    /// Report that `done` out of `total` units of work have been completed.
+    ///
+    /// Returns `true` if the update was written.
+    #[inline]
+    pub fn update(&mut self, done: u64, total: u64) -> bool {
+        self.update_at(done, total, Instant::now())
+    }
+
+    /// Report progress as if the current time was `now`.
+    ///
+    /// Returns `true` if the update was written.
+    pub fn update_at(&mut self, done: u64, total: u64, now: Instant) -> bool {
+        let Some(output) = &mut self.output else {
+            return false;
+        };
+
+        output.progress(done, total, now).unwrap_or(false)
+    }
+}
+
+impl Default for Progress {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
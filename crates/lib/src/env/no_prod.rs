@@ -14,7 +14,7 @@ pub struct Size(usize);
 
 impl Size {
     /// Default zero value.
-    pub(crate) const ZERO: Self = Self(0);
+    pub const ZERO: Self = Self(0);
 
     #[inline]
     pub(crate) fn usize_range(range: Range<Size>) -> Range<usize> {
@@ -54,21 +54,17 @@ pub(crate) fn pos_from(data: &[u8], span: Range<Size>) -> LineCol {
     use crate::input::NL;
 
     let span = Size::usize_range(span);
+    let start = span.start.min(data.len());
+    let end = span.end.clamp(start, data.len());
 
-    let Some(d) = data.get(..=span.start) else {
-        return LineCol::EMPTY;
-    };
-
-    let it = memchr::memchr_iter(NL, d);
+    let before = &data[..start];
+    let line = memchr::memchr_iter(NL, before).count();
+    let line_start = memchr::memrchr(NL, before).map_or(0, |n| n + 1);
 
-    let (line, last) = it
-        .enumerate()
-        .last()
-        .map(|(line, n)| (line + 1, n))
-        .unwrap_or_default();
+    // Spans which cross lines are truncated to the line they start on.
+    let end = memchr::memchr(NL, &data[start..end]).map_or(end, |n| start + n);
 
-    let start = d.get(last.saturating_add(1)..).unwrap_or_default().len();
-    LineCol::new(line, start)
+    LineCol::new(line, start - line_start, end - line_start)
 }
 
 /// Input processing.
@@ -3,10 +3,14 @@ use core::ops::Range;
 
 use crate::cli::error::LineCol;
 
+/// Index tracking is disabled in production, so this is zero-sized and every
+/// operation on it compiles away.
 #[repr(transparent)]
 #[derive(Clone, Copy)]
 pub struct Size;
 
+const _: () = assert!(core::mem::size_of::<Size>() == 0);
+
 impl Size {
     /// Default zero value.
     pub const ZERO: Self = Self;
@@ -27,9 +31,7 @@ impl Size {
     }
 
     #[inline]
-    pub(crate) fn advance(&mut self, _: usize) -> Self {
-        Self
-    }
+    pub(crate) fn advance(&mut self, _: usize) {}
 }
 
 impl fmt::Debug for Size {
@@ -43,6 +45,12 @@ pub fn pos_from(_: &[u8], _: Range<Size>) -> LineCol {
     LineCol::EMPTY
 }
 
+/// Embed the input at `inputs/<path>` relative to the crate being built.
+///
+/// The input is included with [`include_bytes!`], so production binaries are
+/// self-contained and don't read anything from the filesystem at runtime.
+/// The second argument is the storage capacity used by the development
+/// version of this macro and is ignored here.
 #[macro_export]
 macro_rules! input {
     ($path:literal) => {
@@ -1,7 +1,168 @@
+use core::hash::Hash;
+use std::collections::{HashMap, HashSet};
+
 pub trait SliceExt<O> {
     /// Get two values out of a slice, assuming they are disjoint and in bounds.
     /// Else will return `None`.
     fn get_mut2(&mut self, a: usize, b: usize) -> Option<(&mut O, &mut O)>;
+
+    /// Test if every element in the slice is distinct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// assert!(b"abcd".all_distinct());
+    /// assert!(!b"abca".all_distinct());
+    /// assert!([0u32; 0].all_distinct());
+    /// ```
+    fn all_distinct(&self) -> bool
+    where
+        O: Eq + Hash;
+
+    /// Iterate over non-overlapping chunks of `N` elements as arrays.
+    ///
+    /// A trailing remainder shorter than `N` is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4, 5];
+    /// let chunks = values.chunks_exact_array::<2>().collect::<Vec<_>>();
+    /// assert_eq!(chunks, [[1, 2], [3, 4]]);
+    /// ```
+    fn chunks_exact_array<const N: usize>(&self) -> impl Iterator<Item = [O; N]>
+    where
+        O: Copy;
+
+    /// Iterate over overlapping windows of `N` elements as arrays.
+    ///
+    /// Nothing is produced if the slice is shorter than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4];
+    /// assert!(values.windows_array::<2>().eq([[1, 2], [2, 3], [3, 4]]));
+    /// assert!(values.windows_array::<3>().eq([[1, 2, 3], [2, 3, 4]]));
+    /// assert_eq!(values.windows_array::<5>().count(), 0);
+    /// ```
+    fn windows_array<const N: usize>(&self) -> impl Iterator<Item = [O; N]>
+    where
+        O: Copy;
+
+    /// Iterate over maximal runs of consecutive elements which have an equal
+    /// `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 1, 2, 2, 2, 3];
+    /// let runs = values.group_runs(|n| *n).collect::<Vec<_>>();
+    /// assert_eq!(runs, [&[1, 1][..], &[2, 2, 2], &[3]]);
+    ///
+    /// let values = [1, 3, 2, 4, 5];
+    /// let runs = values.group_runs(|n| n % 2).collect::<Vec<_>>();
+    /// assert_eq!(runs, [&[1, 3][..], &[2, 4], &[5]]);
+    ///
+    /// assert_eq!([0u32; 0].group_runs(|n| *n).count(), 0);
+    /// ```
+    fn group_runs<'a, K, F>(&'a self, key: F) -> impl Iterator<Item = &'a [O]>
+    where
+        O: 'a,
+        F: Fn(&O) -> K,
+        K: PartialEq;
+
+    /// Get the index of the smallest element in the slice, or `None` if the
+    /// slice is empty.
+    ///
+    /// If several elements are equally small, the index of the first one is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// assert_eq!([3, 1, 2].argmin(), Some(1));
+    /// assert_eq!([2, 1, 3, 1].argmin(), Some(1));
+    /// assert_eq!([0u32; 0].argmin(), None);
+    /// ```
+    fn argmin(&self) -> Option<usize>
+    where
+        O: Ord;
+
+    /// Get the index of the largest element in the slice, or `None` if the
+    /// slice is empty.
+    ///
+    /// If several elements are equally large, the index of the first one is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// assert_eq!([1, 3, 2].argmax(), Some(1));
+    /// assert_eq!([1, 3, 2, 3].argmax(), Some(1));
+    /// assert_eq!([0u32; 0].argmax(), None);
+    /// ```
+    fn argmax(&self) -> Option<usize>
+    where
+        O: Ord;
+
+    /// Get the index of the element with the smallest `key`, or `None` if the
+    /// slice is empty.
+    ///
+    /// If several elements have an equally small key, the index of the first
+    /// one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [(1, 'a'), (0, 'b'), (0, 'c')];
+    /// assert_eq!(values.argmin_by_key(|(n, _)| *n), Some(1));
+    /// ```
+    fn argmin_by_key<K, F>(&self, key: F) -> Option<usize>
+    where
+        F: FnMut(&O) -> K,
+        K: Ord;
+
+    /// Get the index of the element with the largest `key`, or `None` if the
+    /// slice is empty.
+    ///
+    /// If several elements have an equally large key, the index of the first
+    /// one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [(1, 'a'), (2, 'b'), (2, 'c')];
+    /// assert_eq!(values.argmax_by_key(|(n, _)| *n), Some(1));
+    /// ```
+    fn argmax_by_key<K, F>(&self, key: F) -> Option<usize>
+    where
+        F: FnMut(&O) -> K,
+        K: Ord;
 }
 
 impl<T> SliceExt<T> for [T] {
@@ -20,4 +181,152 @@ impl<T> SliceExt<T> for [T] {
             Some((a, b))
         }
     }
+
+    #[inline]
+    fn all_distinct(&self) -> bool
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::with_capacity(self.len());
+        self.iter().all(|value| seen.insert(value))
+    }
+
+    #[inline]
+    fn chunks_exact_array<const N: usize>(&self) -> impl Iterator<Item = [T; N]>
+    where
+        T: Copy,
+    {
+        self.chunks_exact(N)
+            .filter_map(|chunk| <[T; N]>::try_from(chunk).ok())
+    }
+
+    #[inline]
+    fn windows_array<const N: usize>(&self) -> impl Iterator<Item = [T; N]>
+    where
+        T: Copy,
+    {
+        self.windows(N)
+            .filter_map(|window| <[T; N]>::try_from(window).ok())
+    }
+
+    #[inline]
+    fn group_runs<'a, K, F>(&'a self, key: F) -> impl Iterator<Item = &'a [T]>
+    where
+        T: 'a,
+        F: Fn(&T) -> K,
+        K: PartialEq,
+    {
+        self.chunk_by(move |a, b| key(a) == key(b))
+    }
+
+    #[inline]
+    fn argmin(&self) -> Option<usize>
+    where
+        T: Ord,
+    {
+        first_index_by(self, |value, best| value < best)
+    }
+
+    #[inline]
+    fn argmax(&self) -> Option<usize>
+    where
+        T: Ord,
+    {
+        first_index_by(self, |value, best| value > best)
+    }
+
+    #[inline]
+    fn argmin_by_key<K, F>(&self, key: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        first_index_by_key(self, key, |value, best| value < best)
+    }
+
+    #[inline]
+    fn argmax_by_key<K, F>(&self, key: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        first_index_by_key(self, key, |value, best| value > best)
+    }
+}
+
+/// Find the index of the element which is preferred by `better`, keeping the
+/// first one on ties.
+fn first_index_by<T>(values: &[T], better: impl Fn(&T, &T) -> bool) -> Option<usize> {
+    let mut it = values.iter().enumerate();
+    let (mut index, mut best) = it.next()?;
+
+    for (n, value) in it {
+        if better(value, best) {
+            index = n;
+            best = value;
+        }
+    }
+
+    Some(index)
+}
+
+/// Find the index of the element whose key is preferred by `better`, keeping
+/// the first one on ties.
+fn first_index_by_key<T, K>(
+    values: &[T],
+    mut key: impl FnMut(&T) -> K,
+    better: impl Fn(&K, &K) -> bool,
+) -> Option<usize> {
+    let mut it = values.iter().enumerate();
+    let (mut index, first) = it.next()?;
+    let mut best = key(first);
+
+    for (n, value) in it {
+        let value = key(value);
+
+        if better(&value, &best) {
+            index = n;
+            best = value;
+        }
+    }
+
+    Some(index)
+}
+
+/// Find the first window of `k` consecutive elements in `iter` which are all
+/// distinct, returning the index just past the end of the window.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+///
+/// let input = b"mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+/// assert_eq!(first_distinct_window(input, 4), Some(7));
+/// assert_eq!(first_distinct_window(input, 14), Some(19));
+/// assert_eq!(first_distinct_window(b"aaaa", 2), None);
+/// ```
+pub fn first_distinct_window<I>(iter: I, k: usize) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: Eq + Hash,
+{
+    if k == 0 {
+        return Some(0);
+    }
+
+    let mut last = HashMap::new();
+    let mut start = 0;
+
+    for (n, value) in iter.into_iter().enumerate() {
+        if let Some(prev) = last.insert(value, n) {
+            start = start.max(prev + 1);
+        }
+
+        if n + 1 - start >= k {
+            return Some(n + 1);
+        }
+    }
+
+    None
 }
@@ -1,13 +1,22 @@
 pub mod slice;
 
+mod owned;
+pub use self::owned::{OwnedGrid, RaggedRows};
+
 mod sealed {
     pub trait Sealed {}
     impl<T> Sealed for [T] {}
+    impl<T, const N: usize> Sealed for [T; N] {}
+    impl<T> Sealed for Vec<T> {}
 }
 
+use core::fmt::{self, Write};
+use core::marker::PhantomData;
+
 use arrayvec::ArrayVec;
 
 use self::sealed::Sealed;
+use self::slice::SliceGrid;
 
 pub trait GridExt<T>: Sealed {
     /// Return value as an immutable grid.
@@ -122,6 +131,38 @@ pub trait Grid<T> {
     /// Get number of columns in the grid.
     fn columns_len(&self) -> usize;
 
+    /// Borrow a `rows` by `columns` view of the grid starting at the given
+    /// `row` and `column`.
+    ///
+    /// Returns `None` if the view doesn't fit inside of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+    /// let grid = values.as_grid(4);
+    ///
+    /// let inner = grid.submatrix(1, 1, 2, 2).unwrap();
+    /// assert_eq!(inner.rows_len(), 2);
+    /// assert_eq!(inner.columns_len(), 2);
+    /// assert!(inner.rows().flatten().copied().eq([6, 7, 10, 11]));
+    /// assert!(inner.columns().flatten().copied().eq([6, 10, 7, 11]));
+    /// assert_eq!(*inner.get(1, 0), 10);
+    /// assert!(inner.try_get(2, 0).is_none());
+    ///
+    /// assert!(grid.submatrix(3, 3, 2, 2).is_none());
+    /// assert!(grid.submatrix(0, 0, 4, 4).is_some());
+    /// ```
+    fn submatrix(
+        &self,
+        row: usize,
+        column: usize,
+        rows: usize,
+        columns: usize,
+    ) -> Option<SliceGrid<'_, T>>;
+
     /// Get the element at the given row and column.
     #[inline]
     #[track_caller]
@@ -138,6 +179,129 @@ pub trait Grid<T> {
         self.row(row)?.into_ref(column)
     }
 
+    /// Get the element at the given row and column, where coordinates outside
+    /// of the grid wrap around its edges as if it was a torus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6];
+    /// let grid = values.as_grid(3);
+    ///
+    /// assert_eq!(*grid.wrapping_get(-1, -1), 6);
+    /// assert_eq!(*grid.wrapping_get(0, 0), 1);
+    /// assert_eq!(*grid.wrapping_get(2, 3), 1);
+    /// assert_eq!(*grid.wrapping_get(1_000_001, 1_000_001), 6);
+    /// assert_eq!(*grid.wrapping_get(-3, -4), 6);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn wrapping_get(&self, row: isize, column: isize) -> &T {
+        let (rows, columns) = (self.rows_len(), self.columns_len());
+        assert!(rows != 0 && columns != 0, "cannot wrap in an empty grid");
+        let row = row.rem_euclid(rows as isize) as usize;
+        let column = column.rem_euclid(columns as isize) as usize;
+        self.get(row, column)
+    }
+
+    /// Iterate over the four orthogonal neighbors of the given cell as `(row,
+    /// column, value)`, where neighbors outside of the grid wrap around its
+    /// edges as if it was a torus.
+    ///
+    /// Neighbors are yielded in the order up, right, down, left. In grids
+    /// which are narrower than three cells in some dimension the same cell
+    /// might be yielded more than once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let grid = values.as_grid(3);
+    ///
+    /// let n = grid.wrapping_neighbors4(0, 0).collect::<Vec<_>>();
+    /// assert_eq!(n, [(2, 0, &7), (0, 1, &2), (1, 0, &4), (0, 2, &3)]);
+    ///
+    /// let n = grid.wrapping_neighbors4(1, 1).map(|(_, _, v)| *v).collect::<Vec<_>>();
+    /// assert_eq!(n, [2, 6, 8, 4]);
+    /// ```
+    #[track_caller]
+    fn wrapping_neighbors4<'a>(
+        &'a self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        let (rows, columns) = (self.rows_len(), self.columns_len());
+        assert!(rows != 0 && columns != 0, "cannot wrap in an empty grid");
+
+        let (r, c) = (row % rows, column % columns);
+        let up = ((r + rows - 1) % rows, c);
+        let right = (r, (c + 1) % columns);
+        let down = ((r + 1) % rows, c);
+        let left = (r, (c + columns - 1) % columns);
+
+        [up, right, down, left]
+            .into_iter()
+            .map(move |(r, c)| (r, c, self.get(r, c)))
+    }
+
+    /// Count the in-bounds neighbors among the eight surrounding the given
+    /// cell for which `pred` returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// // A glider.
+    /// let values = *b".#...####";
+    /// let grid = values.as_grid(3);
+    /// let live = |b: &u8| *b == b'#';
+    ///
+    /// assert_eq!(grid.count_neighbors8(0, 0, live), 1);
+    /// assert_eq!(grid.count_neighbors8(1, 0, live), 3);
+    /// assert_eq!(grid.count_neighbors8(1, 1, live), 5);
+    /// assert_eq!(grid.count_neighbors8(2, 2, live), 2);
+    /// ```
+    fn count_neighbors8(&self, row: usize, column: usize, pred: impl Fn(&T) -> bool) -> usize {
+        count_neighbors(self, row, column, &NEIGHBORS8, pred)
+    }
+
+    /// Count the in-bounds orthogonal neighbors of the given cell for which
+    /// `pred` returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// // A glider.
+    /// let values = *b".#...####";
+    /// let grid = values.as_grid(3);
+    /// let live = |b: &u8| *b == b'#';
+    ///
+    /// assert_eq!(grid.count_neighbors4(0, 0, live), 1);
+    /// assert_eq!(grid.count_neighbors4(1, 1, live), 3);
+    /// assert_eq!(grid.count_neighbors4(2, 2, live), 2);
+    /// ```
+    fn count_neighbors4(&self, row: usize, column: usize, pred: impl Fn(&T) -> bool) -> usize {
+        count_neighbors(self, row, column, &NEIGHBORS4, pred)
+    }
+
     /// Collect an iterator of rows and columns into an array.
     ///
     /// This collects up until the array is full, an incorrect index is
@@ -164,6 +328,293 @@ pub trait Grid<T> {
 
         values
     }
+
+    /// Iterate over every cell on the border of the grid exactly once as
+    /// `(row, column, value)`.
+    ///
+    /// Cells are yielded clockwise starting in the top left corner: the top
+    /// row left to right, the right column top to bottom, the bottom row right
+    /// to left and finally the left column bottom to top.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let grid = values.as_grid(3);
+    /// assert!(grid.border().map(|(_, _, v)| *v).eq([1, 2, 3, 6, 9, 8, 7, 4]));
+    ///
+    /// let values = [1, 2, 3, 4];
+    /// let grid = values.as_grid(4);
+    /// assert!(grid.border().map(|(r, c, _)| (r, c)).eq([(0, 0), (0, 1), (0, 2), (0, 3)]));
+    ///
+    /// let grid = values.as_grid(1);
+    /// assert!(grid.border().map(|(r, c, _)| (r, c)).eq([(0, 0), (1, 0), (2, 0), (3, 0)]));
+    ///
+    /// let values = [1];
+    /// let grid = values.as_grid(1);
+    /// assert!(grid.border().map(|(r, c, v)| (r, c, *v)).eq([(0, 0, 1)]));
+    /// ```
+    fn border<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        let (rows, columns) = match (self.rows_len(), self.columns_len()) {
+            (0, _) | (_, 0) => (0, 0),
+            dims => dims,
+        };
+
+        let last_row = rows.saturating_sub(1);
+        let last_column = columns.saturating_sub(1);
+
+        let top = (0..columns).map(|c| (0, c));
+        let right = (1..rows).map(move |r| (r, last_column));
+        let bottom = (0..if rows > 1 { last_column } else { 0 })
+            .rev()
+            .map(move |c| (last_row, c));
+        let left = (1..if columns > 1 { last_row } else { 1 })
+            .rev()
+            .map(|r| (r, 0));
+
+        top.chain(right)
+            .chain(bottom)
+            .chain(left)
+            .map(move |(r, c)| (r, c, self.get(r, c)))
+    }
+
+    /// Iterate over every cell in the grid in row-major order as `(row,
+    /// column, value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6];
+    /// let grid = values.as_grid(3);
+    ///
+    /// let cells = grid.cells().map(|(r, c, v)| (r, c, *v)).collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     cells,
+    ///     [(0, 0, 1), (0, 1, 2), (0, 2, 3), (1, 0, 4), (1, 1, 5), (1, 2, 6)]
+    /// );
+    ///
+    /// // Elements outside of the grid are skipped when using a stride.
+    /// let values = [1, 2, 0, 3, 4, 0];
+    /// let grid = values.as_grid_with_stride(2, 1);
+    ///
+    /// let cells = grid.cells().map(|(r, c, v)| (r, c, *v)).collect::<Vec<_>>();
+    /// assert_eq!(cells, [(0, 0, 1), (0, 1, 2), (1, 0, 3), (1, 1, 4)]);
+    /// ```
+    fn cells<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        self.rows().enumerate().flat_map(|(r, row)| {
+            row.into_iter()
+                .enumerate()
+                .map(move |(c, value)| (r, c, value))
+        })
+    }
+
+    /// Construct a [fmt::Display] adapter which prints the grid row by row,
+    /// mapping each cell to a character using `f`.
+    ///
+    /// Rows are separated by newlines, and no trailing newline is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [true, false, false, true];
+    /// let grid = values.as_grid(2);
+    ///
+    /// let output = grid.display(|v| if *v { '#' } else { '.' }).to_string();
+    /// assert_eq!(output, "#.\n.#");
+    /// ```
+    fn display<'a, F>(&'a self, f: F) -> impl fmt::Display + 'a
+    where
+        F: 'a + Fn(&T) -> char,
+        T: 'a,
+    {
+        Display {
+            grid: self,
+            f,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Iterate over overlapping pairs of adjacent rows as `(row, next_row)`.
+    ///
+    /// Grids with fewer than two rows produce no pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6];
+    /// let grid = values.as_grid(2);
+    ///
+    /// let pairs = grid
+    ///     .row_pairs()
+    ///     .map(|(a, b)| (a.as_ref().to_vec(), b.as_ref().to_vec()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(pairs, [(vec![1, 2], vec![3, 4]), (vec![3, 4], vec![5, 6])]);
+    ///
+    /// let values = [1, 2];
+    /// let grid = values.as_grid(2);
+    /// assert_eq!(grid.row_pairs().count(), 0);
+    /// ```
+    fn row_pairs<'a>(&'a self) -> impl Iterator<Item = (Self::Row<'a>, Self::Row<'a>)>
+    where
+        T: 'a,
+    {
+        self.rows().zip(self.rows().skip(1))
+    }
+
+    /// Iterate over overlapping pairs of adjacent columns as `(column,
+    /// next_column)`.
+    ///
+    /// Grids with fewer than two columns produce no pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6];
+    /// let grid = values.as_grid(3);
+    ///
+    /// let pairs = grid
+    ///     .column_pairs()
+    ///     .map(|(a, b)| (a.into_iter().copied().collect(), b.into_iter().copied().collect()))
+    ///     .collect::<Vec<(Vec<_>, Vec<_>)>>();
+    ///
+    /// assert_eq!(pairs, [(vec![1, 4], vec![2, 5]), (vec![2, 5], vec![3, 6])]);
+    ///
+    /// let values = [1, 2];
+    /// let grid = values.as_grid(1);
+    /// assert_eq!(grid.column_pairs().count(), 0);
+    /// ```
+    fn column_pairs<'a>(&'a self) -> impl Iterator<Item = (Self::Column<'a>, Self::Column<'a>)>
+    where
+        T: 'a,
+    {
+        self.columns().zip(self.columns().skip(1))
+    }
+
+    /// Construct a copy of the grid rotated 90 degrees clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6];
+    /// let grid = values.as_grid(3);
+    ///
+    /// let rotated = grid.rotate_cw();
+    /// assert_eq!(rotated.rows_len(), 3);
+    /// assert_eq!(rotated.columns_len(), 2);
+    /// assert_eq!(rotated.as_slice(), &[4, 1, 5, 2, 6, 3]);
+    ///
+    /// let identity = rotated.rotate_cw().rotate_cw().rotate_cw();
+    /// assert_eq!(identity.as_slice(), &values);
+    /// ```
+    fn rotate_cw(&self) -> OwnedGrid<T>
+    where
+        T: Copy,
+    {
+        let rows = self.rows_len();
+        let mut data = Vec::with_capacity(rows * self.columns_len());
+
+        for c in 0..self.columns_len() {
+            for r in (0..rows).rev() {
+                data.push(*self.get(r, c));
+            }
+        }
+
+        OwnedGrid::new(data, rows)
+    }
+
+    /// Construct a copy of the grid rotated 90 degrees counter-clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6];
+    /// let grid = values.as_grid(3);
+    ///
+    /// let rotated = grid.rotate_ccw();
+    /// assert_eq!(rotated.as_slice(), &[3, 6, 2, 5, 1, 4]);
+    /// assert_eq!(rotated.rotate_cw().as_slice(), &values);
+    /// ```
+    fn rotate_ccw(&self) -> OwnedGrid<T>
+    where
+        T: Copy,
+    {
+        let rows = self.rows_len();
+        let mut data = Vec::with_capacity(rows * self.columns_len());
+
+        for c in (0..self.columns_len()).rev() {
+            for r in 0..rows {
+                data.push(*self.get(r, c));
+            }
+        }
+
+        OwnedGrid::new(data, rows)
+    }
+
+    /// Construct a copy of the grid enlarged by `thickness` on all sides,
+    /// where the added border is filled with `fill`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let values = [1, 2, 3, 4];
+    /// let grid = values.as_grid(2);
+    ///
+    /// let padded = grid.pad(1, 0);
+    /// assert_eq!(padded.rows_len(), 4);
+    /// assert_eq!(padded.columns_len(), 4);
+    ///
+    /// assert_eq!(
+    ///     padded.as_slice(),
+    ///     &[
+    ///         0, 0, 0, 0,
+    ///         0, 1, 2, 0,
+    ///         0, 3, 4, 0,
+    ///         0, 0, 0, 0,
+    ///     ]
+    /// );
+    /// ```
+    fn pad(&self, thickness: usize, fill: T) -> OwnedGrid<T>
+    where
+        T: Copy,
+    {
+        let columns = self.columns_len() + thickness * 2;
+        let rows = self.rows_len() + thickness * 2;
+        let mut data = Vec::with_capacity(rows * columns);
+
+        data.resize(thickness * columns, fill);
+
+        for row in self.rows() {
+            data.extend((0..thickness).map(|_| fill));
+            data.extend(row.into_iter().copied());
+            data.extend((0..thickness).map(|_| fill));
+        }
+
+        data.resize(rows * columns, fill);
+        OwnedGrid::new(data, columns)
+    }
 }
 
 impl<G, T> Grid<T> for &G
@@ -219,6 +670,17 @@ where
     fn columns_len(&self) -> usize {
         (**self).columns_len()
     }
+
+    #[inline]
+    fn submatrix(
+        &self,
+        row: usize,
+        column: usize,
+        rows: usize,
+        columns: usize,
+    ) -> Option<SliceGrid<'_, T>> {
+        (**self).submatrix(row, column, rows, columns)
+    }
 }
 
 pub trait GridMut<T>: Grid<T> {
@@ -308,8 +770,252 @@ pub trait GridMut<T>: Grid<T> {
     fn try_get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
         self.row_mut(row)?.into_mut(column)
     }
+
+    /// Mutably iterate over every cell in the grid in row-major order as
+    /// `(row, column, value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut values = [0, 0, 9, 0, 0, 9];
+    /// let mut grid = values.as_grid_mut_with_stride(2, 1);
+    ///
+    /// for (r, c, value) in grid.cells_mut() {
+    ///     *value = r * 10 + c;
+    /// }
+    ///
+    /// assert_eq!(values, [0, 1, 9, 10, 11, 9]);
+    /// ```
+    fn cells_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, usize, &'a mut T)>
+    where
+        T: 'a,
+    {
+        self.rows_mut().enumerate().flat_map(|(r, row)| {
+            row.into_iter()
+                .enumerate()
+                .map(move |(c, value)| (r, c, value))
+        })
+    }
+
+    /// Set every cell in the grid to `value`.
+    ///
+    /// Elements outside of the grid, such as stride padding, are left as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut values = [1, 2, 0, 3, 4, 0];
+    /// let mut grid = values.as_grid_mut_with_stride(2, 1);
+    /// grid.fill(7);
+    /// assert_eq!(values, [7, 7, 0, 7, 7, 0]);
+    /// ```
+    fn fill(&mut self, value: T)
+    where
+        T: Copy,
+    {
+        for row in self.rows_mut() {
+            for cell in row {
+                *cell = value;
+            }
+        }
+    }
+
+    /// Set every cell in the given `row` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut values = [1, 2, 0, 3, 4, 0];
+    /// let mut grid = values.as_grid_mut_with_stride(2, 1);
+    /// grid.fill_row(1, 7);
+    /// assert_eq!(values, [1, 2, 0, 7, 7, 0]);
+    /// ```
+    fn fill_row(&mut self, row: usize, value: T)
+    where
+        T: Copy,
+    {
+        let Some(cells) = self.row_mut(row) else {
+            panic!("missing row `{row}`");
+        };
+
+        for cell in cells {
+            *cell = value;
+        }
+    }
+
+    /// Set every cell in the given `column` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `column` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut values = [1, 2, 0, 3, 4, 0];
+    /// let mut grid = values.as_grid_mut_with_stride(2, 1);
+    /// grid.fill_column(1, 7);
+    /// assert_eq!(values, [1, 7, 0, 3, 7, 0]);
+    /// ```
+    fn fill_column(&mut self, column: usize, value: T)
+    where
+        T: Copy,
+    {
+        let Some(cells) = self.column_mut(column) else {
+            panic!("missing column `{column}`");
+        };
+
+        for cell in cells {
+            *cell = value;
+        }
+    }
+
+    /// Advance a cellular automaton by one step.
+    ///
+    /// The next state of every cell is computed by calling `rule` with its
+    /// current value and the number of its eight neighbors which are `live`.
+    /// Every next state is computed into a scratch buffer before being
+    /// written back, so updated cells don't affect their neighbors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// // A blinker.
+    /// let mut values = *b".#..#..#.";
+    /// let mut grid = values.as_grid_mut(3);
+    ///
+    /// let live = |b: &u8| *b == b'#';
+    ///
+    /// let conway = |b: &u8, n: usize| match (*b, n) {
+    ///     (b'#', 2 | 3) | (b'.', 3) => b'#',
+    ///     _ => b'.',
+    /// };
+    ///
+    /// grid.step(live, conway);
+    /// assert_eq!(&values, b"...###...");
+    ///
+    /// let mut grid = values.as_grid_mut(3);
+    /// grid.step(live, conway);
+    /// assert_eq!(&values, b".#..#..#.");
+    /// ```
+    fn step(&mut self, live: impl Fn(&T) -> bool, rule: impl Fn(&T, usize) -> T)
+    where
+        T: Copy,
+    {
+        let mut next = Vec::with_capacity(self.rows_len() * self.columns_len());
+
+        for (r, c, value) in self.cells() {
+            next.push(rule(value, self.count_neighbors8(r, c, &live)));
+        }
+
+        for ((_, _, cell), value) in self.cells_mut().zip(next) {
+            *cell = value;
+        }
+    }
+
+    /// Swap the elements at the `(row, column)` positions `a` and `b`.
+    ///
+    /// Swapping a cell with itself does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either position is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut values = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let mut grid = values.as_grid_mut(3);
+    ///
+    /// grid.swap((0, 1), (2, 1));
+    /// grid.swap((1, 1), (1, 1));
+    /// assert_eq!(values, [1, 8, 3, 4, 5, 6, 7, 2, 9]);
+    ///
+    /// let mut grid = OwnedGrid::new(vec![1, 2, 3, 4, 5, 6], 3);
+    /// grid.swap((0, 0), (1, 2));
+    /// grid.swap((0, 1), (0, 2));
+    /// assert_eq!(grid.into_rows(), [vec![6, 3, 2], vec![4, 5, 1]]);
+    /// ```
+    fn swap(&mut self, a: (usize, usize), b: (usize, usize));
+}
+
+/// Helpers for grids of bytes.
+pub trait GridBytes: Grid<u8> {
+    /// Access the bytes of the specified row.
+    ///
+    /// The returned slice only covers the columns of the row, so any stride
+    /// padding such as trailing newlines is excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let data = b"abc\ndef\n";
+    /// let grid = data.as_grid_with_stride(3, 1);
+    ///
+    /// assert_eq!(grid.row_bytes(0), Some(&b"abc"[..]));
+    /// assert_eq!(grid.row_bytes(1), Some(&b"def"[..]));
+    /// assert_eq!(grid.row_bytes(2), None);
+    /// assert!(grid.rows_bytes().eq([&b"abc"[..], &b"def"[..]]));
+    /// ```
+    #[inline]
+    fn row_bytes<'a>(&'a self, row: usize) -> Option<&'a [u8]>
+    where
+        Self::Row<'a>: Into<&'a [u8]>,
+    {
+        Some(self.row(row)?.into())
+    }
+
+    /// Iterate over the bytes of each row in the grid.
+    ///
+    /// See [GridBytes::row_bytes].
+    #[inline]
+    fn rows_bytes<'a>(&'a self) -> impl Iterator<Item = &'a [u8]>
+    where
+        Self::Row<'a>: Into<&'a [u8]>,
+    {
+        self.rows().map(Into::into)
+    }
+
+    /// Construct a [fmt::Display] adapter which prints each byte in the grid
+    /// as a character.
+    ///
+    /// See [Grid::display].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let data = b"#.\n.#\n";
+    /// let grid = data.as_grid_with_stride(2, 1);
+    /// assert_eq!(grid.display_bytes().to_string(), "#.\n.#");
+    /// ```
+    #[inline]
+    fn display_bytes(&self) -> impl fmt::Display + '_ {
+        self.display(|b| *b as char)
+    }
 }
 
+impl<G> GridBytes for G where G: ?Sized + Grid<u8> {}
+
 /// The slice into a grid.
 pub trait GridSliceRef<'a, T: 'a> {
     /// Iterator over the grid slice.
@@ -383,3 +1089,75 @@ pub trait GridSliceMut<'a, T: 'a>: GridSliceRef<'a, T> {
     /// Construct a mutable iterator over the slice.
     fn iter_mut(&mut self) -> Self::IterMut<'_>;
 }
+
+/// Offsets of the orthogonal neighbors of a cell.
+const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of all neighbors of a cell.
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// Count the in-bounds neighbors at the given `offsets` for which `pred`
+/// returns `true`.
+fn count_neighbors<G, T>(
+    grid: &G,
+    row: usize,
+    column: usize,
+    offsets: &[(isize, isize)],
+    pred: impl Fn(&T) -> bool,
+) -> usize
+where
+    G: ?Sized + Grid<T>,
+{
+    let mut count = 0;
+
+    for &(dr, dc) in offsets {
+        let (Some(r), Some(c)) = (row.checked_add_signed(dr), column.checked_add_signed(dc)) else {
+            continue;
+        };
+
+        if grid.try_get(r, c).is_some_and(&pred) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// See [Grid::display].
+struct Display<'a, G, T, F>
+where
+    G: ?Sized,
+{
+    grid: &'a G,
+    f: F,
+    _marker: PhantomData<fn(&T)>,
+}
+
+impl<G, T, F> fmt::Display for Display<'_, G, T, F>
+where
+    G: ?Sized + Grid<T>,
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (n, row) in self.grid.rows().enumerate() {
+            if n > 0 {
+                f.write_char('\n')?;
+            }
+
+            for value in row {
+                f.write_char((self.f)(value))?;
+            }
+        }
+
+        Ok(())
+    }
+}
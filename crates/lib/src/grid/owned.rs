@@ -0,0 +1,365 @@
+use core::fmt;
+use core::ptr;
+
+use crate::grid::slice::{
+    submatrix, Column, ColumnMut, Columns, ColumnsMut, Dims, Row, RowMut, Rows, RowsMut, SliceGrid,
+};
+use crate::grid::{Grid, GridMut};
+
+/// Error raised when constructing an [OwnedGrid] out of rows of differing
+/// lengths.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RaggedRows {
+    /// The index of the first mismatching row.
+    pub row: usize,
+    /// The expected number of columns, as determined by the first row.
+    pub expected: usize,
+    /// The actual number of columns in the mismatching row.
+    pub actual: usize,
+}
+
+impl fmt::Display for RaggedRows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "row {} has {} columns, but expected {}",
+            self.row, self.actual, self.expected
+        )
+    }
+}
+
+impl std::error::Error for RaggedRows {}
+
+/// A grid which owns its data.
+#[derive(Clone, PartialEq, Eq)]
+pub struct OwnedGrid<T> {
+    data: Vec<T>,
+    dims: Dims,
+}
+
+impl<T> OwnedGrid<T> {
+    /// Construct a grid out of row-major `data` where each row has the
+    /// specified number of `columns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` is not a multiple of `columns`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let grid = OwnedGrid::new(vec![1, 2, 3, 4, 5, 6], 3);
+    /// assert_eq!(grid.rows_len(), 2);
+    /// assert_eq!(grid.columns_len(), 3);
+    /// assert_eq!(*grid.get(1, 0), 4);
+    /// ```
+    #[track_caller]
+    pub fn new(data: Vec<T>, columns: usize) -> Self {
+        let rows = data.len().checked_div(columns).unwrap_or_default();
+
+        assert!(
+            rows * columns == data.len(),
+            "data of length {} cannot be split into rows of {columns} columns",
+            data.len()
+        );
+
+        Self {
+            data,
+            dims: Dims {
+                rows,
+                columns,
+                stride: columns,
+            },
+        }
+    }
+
+    /// Construct a `rows` by `columns` grid out of row-major `data`.
+    ///
+    /// Unlike [OwnedGrid::new] this preserves the number of rows even if
+    /// they are empty.
+    #[track_caller]
+    pub(crate) fn from_parts(data: Vec<T>, rows: usize, columns: usize) -> Self {
+        assert!(
+            rows.checked_mul(columns) == Some(data.len()),
+            "data of length {} cannot be split into {rows} rows of {columns} columns",
+            data.len()
+        );
+
+        Self {
+            data,
+            dims: Dims {
+                rows,
+                columns,
+                stride: columns,
+            },
+        }
+    }
+
+    /// Construct a `rows` by `columns` grid where each element is produced by
+    /// calling `f` with its row and column, in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `rows` or `columns` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let grid = OwnedGrid::from_fn(9, 9, |r, c| (r + 1) * (c + 1));
+    /// assert_eq!(grid.rows_len(), 9);
+    /// assert_eq!(grid.columns_len(), 9);
+    /// assert_eq!(*grid.get(0, 0), 1);
+    /// assert_eq!(*grid.get(2, 3), 12);
+    /// assert_eq!(*grid.get(8, 8), 81);
+    /// assert_eq!(&grid.as_slice()[..4], &[1, 2, 3, 4]);
+    /// ```
+    #[track_caller]
+    pub fn from_fn(rows: usize, columns: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        assert!(
+            rows != 0 && columns != 0,
+            "grid must have non-zero dimensions, but got {rows}x{columns}"
+        );
+
+        let mut data = Vec::with_capacity(rows * columns);
+
+        for r in 0..rows {
+            for c in 0..columns {
+                data.push(f(r, c));
+            }
+        }
+
+        Self::new(data, columns)
+    }
+
+    /// Access the underlying row-major data of the grid.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Coerce the grid into its underlying row-major data.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Coerce the grid into a vector of its rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let grid = OwnedGrid::new(vec![1, 2, 3, 4, 5, 6], 3);
+    /// assert_eq!(grid.into_rows(), [vec![1, 2, 3], vec![4, 5, 6]]);
+    /// ```
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let mut rows = Vec::with_capacity(self.dims.rows);
+        let mut it = self.data.into_iter();
+
+        for _ in 0..self.dims.rows {
+            rows.push(it.by_ref().take(self.dims.columns).collect());
+        }
+
+        rows
+    }
+}
+
+/// Construct a grid out of its rows, erroring with [RaggedRows] if they
+/// differ in length.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+///
+/// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// let grid = OwnedGrid::try_from(rows.clone())?;
+/// assert_eq!(grid.rows_len(), 2);
+/// assert_eq!(grid.columns_len(), 3);
+/// assert_eq!(grid.into_rows(), rows);
+///
+/// let error = OwnedGrid::try_from(vec![vec![1, 2], vec![3]]).unwrap_err();
+/// assert_eq!(error.to_string(), "row 1 has 1 columns, but expected 2");
+///
+/// // The number of rows is preserved even if they are empty.
+/// let rows = vec![Vec::<u32>::new(); 3];
+/// let grid = OwnedGrid::try_from(rows.clone())?;
+/// assert_eq!(grid.rows_len(), 3);
+/// assert_eq!(grid.columns_len(), 0);
+/// assert_eq!(grid.rows().count(), 3);
+/// assert_eq!(grid.into_rows(), rows);
+/// # Ok::<_, Error>(())
+/// ```
+impl<T> TryFrom<Vec<Vec<T>>> for OwnedGrid<T> {
+    type Error = RaggedRows;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let len = rows.len();
+        let columns = rows.first().map(Vec::len).unwrap_or_default();
+        let mut data = Vec::with_capacity(len * columns);
+
+        for (row, values) in rows.into_iter().enumerate() {
+            if values.len() != columns {
+                return Err(RaggedRows {
+                    row,
+                    expected: columns,
+                    actual: values.len(),
+                });
+            }
+
+            data.extend(values);
+        }
+
+        Ok(Self::from_parts(data, len, columns))
+    }
+}
+
+impl<T> Default for OwnedGrid<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Vec::new(), 0)
+    }
+}
+
+impl<T> fmt::Debug for OwnedGrid<T>
+where
+    T: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+impl<T> Grid<T> for OwnedGrid<T> {
+    type Row<'this> = Row<'this, T> where Self: 'this;
+    type Column<'this> = Column<'this, T> where Self: 'this;
+    type Rows<'this> = Rows<'this, T> where Self: 'this;
+    type Columns<'this> = Columns<'this, T> where Self: 'this;
+
+    #[inline]
+    fn rows(&self) -> Self::Rows<'_> {
+        Rows::new(ptr::NonNull::from(&self.data[..]), &self.dims)
+    }
+
+    #[inline]
+    fn columns(&self) -> Self::Columns<'_> {
+        Columns::new(ptr::NonNull::from(&self.data[..]), &self.dims)
+    }
+
+    #[inline]
+    fn row(&self, row: usize) -> Option<Self::Row<'_>> {
+        if row >= self.dims.rows {
+            return None;
+        }
+
+        Some(Row::new(
+            ptr::NonNull::from(&self.data[..]),
+            &self.dims,
+            row,
+        ))
+    }
+
+    #[inline]
+    fn column(&self, column: usize) -> Option<Self::Column<'_>> {
+        if column >= self.dims.columns {
+            return None;
+        }
+
+        Some(Column::new(
+            ptr::NonNull::from(&self.data[..]),
+            &self.dims,
+            column,
+        ))
+    }
+
+    #[inline]
+    fn rows_len(&self) -> usize {
+        self.dims.rows
+    }
+
+    #[inline]
+    fn columns_len(&self) -> usize {
+        self.dims.columns
+    }
+
+    #[inline]
+    fn submatrix(
+        &self,
+        row: usize,
+        column: usize,
+        rows: usize,
+        columns: usize,
+    ) -> Option<SliceGrid<'_, T>> {
+        submatrix(
+            ptr::NonNull::from(&self.data[..]),
+            &self.dims,
+            row,
+            column,
+            rows,
+            columns,
+        )
+    }
+}
+
+impl<T> GridMut<T> for OwnedGrid<T> {
+    type RowMut<'this> = RowMut<'this, T> where Self: 'this;
+    type ColumnMut<'this> = ColumnMut<'this, T> where Self: 'this;
+    type RowsMut<'this> = RowsMut<'this, T> where Self: 'this;
+    type ColumnsMut<'this> = ColumnsMut<'this, T> where Self: 'this;
+
+    #[inline]
+    fn rows_mut(&mut self) -> Self::RowsMut<'_> {
+        RowsMut::new(ptr::NonNull::from(&mut self.data[..]), &self.dims)
+    }
+
+    #[inline]
+    fn columns_mut(&mut self) -> Self::ColumnsMut<'_> {
+        ColumnsMut::new(ptr::NonNull::from(&mut self.data[..]), &self.dims)
+    }
+
+    #[inline]
+    fn row_mut(&mut self, row: usize) -> Option<Self::RowMut<'_>> {
+        if row >= self.dims.rows {
+            return None;
+        }
+
+        Some(RowMut::new(
+            ptr::NonNull::from(&mut self.data[..]),
+            &self.dims,
+            row,
+        ))
+    }
+
+    #[inline]
+    fn column_mut(&mut self, column: usize) -> Option<Self::ColumnMut<'_>> {
+        if column >= self.dims.columns {
+            return None;
+        }
+
+        Some(ColumnMut::new(
+            ptr::NonNull::from(&mut self.data[..]),
+            &self.dims,
+            column,
+        ))
+    }
+
+    #[inline]
+    fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let Some(a_index) = self.dims.index(a) else {
+            panic!("missing row `{}`, column `{}`", a.0, a.1);
+        };
+
+        let Some(b_index) = self.dims.index(b) else {
+            panic!("missing row `{}`, column `{}`", b.0, b.1);
+        };
+
+        self.data.swap(a_index, b_index);
+    }
+}
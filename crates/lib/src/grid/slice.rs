@@ -1,6 +1,9 @@
 mod iter;
 pub use self::iter::{ColumnIter, ColumnIterMut, Columns, ColumnsMut, Rows, RowsMut};
 
+#[cfg(feature = "rayon")]
+mod par;
+
 use core::fmt;
 use core::marker::PhantomData;
 use core::mem;
@@ -9,11 +12,24 @@ use core::slice;
 
 use crate::grid::{Grid, GridExt, GridMut, GridSliceMut, GridSliceRef};
 
-#[derive(Clone, Copy)]
-struct Dims {
-    rows: usize,
-    columns: usize,
-    stride: usize,
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Dims {
+    pub(crate) rows: usize,
+    pub(crate) columns: usize,
+    pub(crate) stride: usize,
+}
+
+impl Dims {
+    /// Translate a `(row, column)` position into an index into the
+    /// underlying data.
+    #[inline]
+    pub(crate) fn index(&self, (row, column): (usize, usize)) -> Option<usize> {
+        if row >= self.rows || column >= self.columns {
+            return None;
+        }
+
+        Some(row * self.stride + column)
+    }
 }
 
 /// A column into a grid slice.
@@ -26,7 +42,7 @@ pub struct Column<'a, T> {
 }
 
 impl<'a, T> Column<'a, T> {
-    fn new(data: ptr::NonNull<[T]>, dims: &'a Dims, column: usize) -> Self {
+    pub(crate) fn new(data: ptr::NonNull<[T]>, dims: &'a Dims, column: usize) -> Self {
         Self {
             data,
             dims,
@@ -90,7 +106,7 @@ unsafe impl<T> Send for Row<'_, T> where T: Send {}
 unsafe impl<T> Sync for Row<'_, T> where T: Sync {}
 
 impl<'a, T> Row<'a, T> {
-    fn new(data: ptr::NonNull<[T]>, dims: &'a Dims, row: usize) -> Self {
+    pub(crate) fn new(data: ptr::NonNull<[T]>, dims: &'a Dims, row: usize) -> Self {
         Self {
             data,
             dims,
@@ -108,6 +124,14 @@ impl<T> AsRef<[T]> for Row<'_, T> {
     }
 }
 
+impl<'a, T> From<Row<'a, T>> for &'a [T] {
+    #[inline]
+    fn from(row: Row<'a, T>) -> Self {
+        // SAFETY: the layout of a row is exactly compatible with a slice.
+        unsafe { row_slice_ref(row.data, row.dims, row.row) }
+    }
+}
+
 impl<T> fmt::Debug for Row<'_, T>
 where
     T: fmt::Debug,
@@ -172,7 +196,7 @@ pub struct ColumnMut<'a, T> {
 }
 
 impl<'a, T> ColumnMut<'a, T> {
-    fn new(data: ptr::NonNull<[T]>, dims: &'a Dims, column: usize) -> Self {
+    pub(crate) fn new(data: ptr::NonNull<[T]>, dims: &'a Dims, column: usize) -> Self {
         Self {
             data,
             dims,
@@ -260,7 +284,7 @@ pub struct RowMut<'a, T> {
 }
 
 impl<'a, T> RowMut<'a, T> {
-    fn new(data: ptr::NonNull<[T]>, dims: &'a Dims, row: usize) -> Self {
+    pub(crate) fn new(data: ptr::NonNull<[T]>, dims: &'a Dims, row: usize) -> Self {
         Self {
             data,
             dims,
@@ -407,6 +431,17 @@ impl<'a, T> Grid<T> for SliceGridMut<'a, T> {
     fn columns_len(&self) -> usize {
         self.dims.columns
     }
+
+    #[inline]
+    fn submatrix(
+        &self,
+        row: usize,
+        column: usize,
+        rows: usize,
+        columns: usize,
+    ) -> Option<SliceGrid<'_, T>> {
+        submatrix(self.data, &self.dims, row, column, rows, columns)
+    }
 }
 
 impl<'a, T> GridMut<T> for SliceGridMut<'a, T> {
@@ -442,6 +477,21 @@ impl<'a, T> GridMut<T> for SliceGridMut<'a, T> {
 
         Some(ColumnMut::new(self.data, &self.dims, column))
     }
+
+    #[inline]
+    fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let Some(a_index) = self.dims.index(a) else {
+            panic!("missing row `{}`, column `{}`", a.0, a.1);
+        };
+
+        let Some(b_index) = self.dims.index(b) else {
+            panic!("missing row `{}`, column `{}`", b.0, b.1);
+        };
+
+        // SAFETY: The grid has exclusive access to its data for as long as it
+        // lives, and we're holding a mutable reference to it.
+        unsafe { self.data.as_mut().swap(a_index, b_index) }
+    }
 }
 
 /// Mutable slice grid.
@@ -506,6 +556,17 @@ impl<'a, T> Grid<T> for SliceGrid<'a, T> {
     fn columns_len(&self) -> usize {
         self.dims.columns
     }
+
+    #[inline]
+    fn submatrix(
+        &self,
+        row: usize,
+        column: usize,
+        rows: usize,
+        columns: usize,
+    ) -> Option<SliceGrid<'_, T>> {
+        submatrix(self.data, &self.dims, row, column, rows, columns)
+    }
 }
 
 impl<T> GridExt<T> for [T] {
@@ -563,6 +624,63 @@ impl<T> GridExt<T> for [T] {
     }
 }
 
+/// Treat a vector as a grid.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+///
+/// let mut values = vec![1, 2, 3, 4, 5, 6, 7, 8];
+/// assert_eq!(values.as_grid(4).rows_len(), 2);
+/// assert_eq!(*values.as_grid(4).get(1, 0), 5);
+///
+/// *values.as_grid_mut(4).get_mut(1, 0) = 10;
+/// assert_eq!(values[4], 10);
+/// ```
+impl<T> GridExt<T> for Vec<T> {
+    type Grid<'this> = SliceGrid<'this, T> where Self: 'this;
+    type GridMut<'this> = SliceGridMut<'this, T> where Self: 'this;
+
+    #[inline]
+    fn as_grid_with_stride(&self, columns: usize, stride: usize) -> SliceGrid<'_, T> {
+        self[..].as_grid_with_stride(columns, stride)
+    }
+
+    #[inline]
+    fn as_grid_mut_with_stride(&mut self, columns: usize, stride: usize) -> SliceGridMut<'_, T> {
+        self[..].as_grid_mut_with_stride(columns, stride)
+    }
+}
+
+/// Treat an array as a grid.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+///
+/// let values = [1, 2, 3, 4, 5, 6, 7, 8];
+/// let grid = values.as_grid(4);
+/// assert_eq!(grid.rows_len(), 2);
+/// assert_eq!(grid.columns_len(), 4);
+/// assert_eq!(*grid.get(1, 3), 8);
+/// ```
+impl<T, const N: usize> GridExt<T> for [T; N] {
+    type Grid<'this> = SliceGrid<'this, T> where Self: 'this;
+    type GridMut<'this> = SliceGridMut<'this, T> where Self: 'this;
+
+    #[inline]
+    fn as_grid_with_stride(&self, columns: usize, stride: usize) -> SliceGrid<'_, T> {
+        self[..].as_grid_with_stride(columns, stride)
+    }
+
+    #[inline]
+    fn as_grid_mut_with_stride(&mut self, columns: usize, stride: usize) -> SliceGridMut<'_, T> {
+        self[..].as_grid_mut_with_stride(columns, stride)
+    }
+}
+
 // Utility functions below.
 //
 // A note on ZST: The base address of the underlying slice can always be treated
@@ -614,6 +732,43 @@ unsafe fn row_index_mut<'a, T>(
     }
 }
 
+/// Construct a view of `rows` by `columns` elements of the grid described by
+/// `data` and `dims`, starting at the given `row` and `column`.
+pub(crate) fn submatrix<'a, T>(
+    data: ptr::NonNull<[T]>,
+    dims: &Dims,
+    row: usize,
+    column: usize,
+    rows: usize,
+    columns: usize,
+) -> Option<SliceGrid<'a, T>> {
+    if row.checked_add(rows)? > dims.rows || column.checked_add(columns)? > dims.columns {
+        return None;
+    }
+
+    let start = if rows == 0 || columns == 0 {
+        0
+    } else {
+        row * dims.stride + column
+    };
+
+    // SAFETY: start is within the bounds of the parent grid as checked above.
+    let data = unsafe {
+        let ptr = (data.as_ptr() as *mut T).add(start);
+        ptr::NonNull::slice_from_raw_parts(ptr::NonNull::new_unchecked(ptr), data.len() - start)
+    };
+
+    Some(SliceGrid {
+        data,
+        dims: Dims {
+            rows,
+            columns,
+            stride: dims.stride,
+        },
+        _marker: PhantomData,
+    })
+}
+
 #[inline]
 unsafe fn row_slice_ref<'a, T>(data: ptr::NonNull<[T]>, dims: &Dims, row: usize) -> &'a [T] {
     let ptr = if mem::size_of::<T>() == 0 {
@@ -17,7 +17,7 @@ macro_rules! single {
 
         impl<'a, T> $name<'a, T> {
             #[inline]
-            pub(super) fn new(data: ptr::NonNull<[T]>, dims: &'a Dims) -> Self {
+            pub(crate) fn new(data: ptr::NonNull<[T]>, dims: &'a Dims) -> Self {
                 Self {
                     data: ptr::NonNull::from(data),
                     range: 0..dims.$dim,
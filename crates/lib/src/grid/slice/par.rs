@@ -0,0 +1,92 @@
+//! Parallel iteration over grid rows, enabled through the `rayon` feature.
+
+use rayon::prelude::*;
+
+use crate::grid::slice::{Dims, SliceGrid, SliceGridMut};
+
+impl<'a, T> SliceGrid<'a, T>
+where
+    T: Sync,
+{
+    /// Iterate over rows in the grid in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let values = (0..1024 * 1024).collect::<Vec<u64>>();
+    /// let grid = values.as_grid_with_stride(1000, 24);
+    ///
+    /// let serial = grid.rows().map(|row| row.as_ref().iter().sum::<u64>()).sum::<u64>();
+    /// let parallel = grid.par_rows().map(|row| row.iter().sum::<u64>()).sum::<u64>();
+    /// assert_eq!(serial, parallel);
+    /// assert_eq!(grid.par_rows().count(), 1024);
+    /// ```
+    pub fn par_rows(&self) -> impl IndexedParallelIterator<Item = &[T]> + '_ {
+        // SAFETY: the grid borrows the underlying data immutably.
+        let data = unsafe { self.data.as_ref() };
+        par_rows(data, &self.dims)
+    }
+}
+
+impl<'a, T> SliceGridMut<'a, T>
+where
+    T: Send + Sync,
+{
+    /// Iterate over rows in the grid in parallel.
+    ///
+    /// See [SliceGrid::par_rows].
+    pub fn par_rows(&self) -> impl IndexedParallelIterator<Item = &[T]> + '_ {
+        // SAFETY: the grid is immutably borrowed for the duration of the
+        // iteration.
+        let data = unsafe { self.data.as_ref() };
+        par_rows(data, &self.dims)
+    }
+
+    /// Iterate mutably over rows in the grid in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut values = [0u32; 12];
+    /// let mut grid = values.as_grid_mut_with_stride(3, 1);
+    ///
+    /// grid.par_rows_mut().enumerate().for_each(|(n, row)| row.fill(n as u32 + 1));
+    /// assert_eq!(values, [1, 1, 1, 0, 2, 2, 2, 0, 3, 3, 3, 0]);
+    /// ```
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]> + '_ {
+        let Dims {
+            rows,
+            columns,
+            stride,
+        } = self.dims;
+
+        // SAFETY: the grid is mutably borrowed for the duration of the
+        // iteration, and each row is a disjoint chunk of the data.
+        let data = unsafe { self.data.as_mut() };
+
+        data.par_chunks_mut(stride)
+            .take(rows)
+            .map(move |row| &mut row[..columns])
+    }
+}
+
+fn par_rows<'a, T>(data: &'a [T], dims: &Dims) -> impl IndexedParallelIterator<Item = &'a [T]>
+where
+    T: Sync,
+{
+    let Dims {
+        rows,
+        columns,
+        stride,
+    } = *dims;
+
+    data.par_chunks(stride)
+        .take(rows)
+        .map(move |row| &row[..columns])
+}
@@ -0,0 +1,190 @@
+//! Heap helpers built on top of [`FixedHeap`].
+
+use core::cmp::Ordering;
+
+use fixed_heap::FixedHeap;
+
+/// A comparison used to order elements in a [`MinHeap`].
+pub trait Compare<T> {
+    /// Compare two elements, where the lesser element is popped first.
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// Order elements using a comparison function.
+///
+/// Constructed through [`MinHeap::by`].
+#[derive(Clone, Copy)]
+pub struct By<F>(F);
+
+impl<T, F> Compare<T> for By<F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.0)(a, b)
+    }
+}
+
+/// Order elements by a key extracted from each element.
+///
+/// Constructed through [`MinHeap::by_key`].
+#[derive(Clone, Copy)]
+pub struct ByKey<F>(F);
+
+impl<T, F, K> Compare<T> for ByKey<F>
+where
+    F: Fn(&T) -> K,
+    K: Ord,
+{
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.0)(a).cmp(&(self.0)(b))
+    }
+}
+
+/// A fixed capacity min-heap which stores its comparison once at
+/// construction.
+///
+/// This wraps [`FixedHeap`] so that the comparer and its state doesn't have to
+/// be passed into every call to `push` and `pop`.
+///
+/// # Examples
+///
+/// Elements with the lowest cost are popped first, exactly like a
+/// [`FixedHeap`] using a `a.0 < b.0` comparer:
+///
+/// ```
+/// use lib::prelude::*;
+///
+/// type Element = (u32, (usize, usize), u8);
+///
+/// let elements: [Element; 5] = [
+///     (3, (0, 0), b'a'),
+///     (1, (1, 0), b'b'),
+///     (4, (2, 0), b'c'),
+///     (0, (0, 1), b'd'),
+///     (2, (1, 1), b'e'),
+/// ];
+///
+/// let comparer = |a: &Element, b: &Element, _: &()| a.0 < b.0;
+/// let mut expected = FixedHeap::<_, 8>::new();
+/// let mut heap = MinHeap::<_, 8, _>::by_key(|e: &Element| e.0);
+///
+/// for e in elements {
+///     assert!(expected.push(e, &comparer, &()).is_none());
+///     assert!(heap.push(e).is_none());
+/// }
+///
+/// assert_eq!(heap.len(), 5);
+///
+/// while let Some(e) = expected.pop(&comparer, &()) {
+///     assert_eq!(heap.pop().map(|e| e.0), Some(e.0));
+/// }
+///
+/// assert!(heap.is_empty());
+/// ```
+pub struct MinHeap<T, const N: usize, C> {
+    heap: FixedHeap<T, N>,
+    cmp: C,
+}
+
+impl<T, F, const N: usize> MinHeap<T, N, By<F>>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    /// Construct a new heap ordered by the given comparison function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut heap = MinHeap::<u32, 4, _>::by(|a, b| b.cmp(a));
+    /// heap.push(1);
+    /// heap.push(3);
+    /// heap.push(2);
+    ///
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), Some(2));
+    /// assert_eq!(heap.pop(), Some(1));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    #[inline]
+    pub fn by(cmp: F) -> Self {
+        Self {
+            heap: FixedHeap::new(),
+            cmp: By(cmp),
+        }
+    }
+}
+
+impl<T, F, const N: usize> MinHeap<T, N, ByKey<F>> {
+    /// Construct a new heap ordered by the key extracted by the given
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let mut heap = MinHeap::<_, 4, _>::by_key(|&(cost, _): &(u32, char)| cost);
+    /// heap.push((2, 'b'));
+    /// heap.push((1, 'a'));
+    ///
+    /// assert_eq!(heap.pop(), Some((1, 'a')));
+    /// assert_eq!(heap.pop(), Some((2, 'b')));
+    /// ```
+    #[inline]
+    pub fn by_key<K>(key: F) -> Self
+    where
+        F: Fn(&T) -> K,
+        K: Ord,
+    {
+        Self {
+            heap: FixedHeap::new(),
+            cmp: ByKey(key),
+        }
+    }
+}
+
+impl<T, const N: usize, C> MinHeap<T, N, C>
+where
+    C: Compare<T>,
+{
+    /// Push an element onto the heap.
+    ///
+    /// If the heap is at capacity, the element is handed back.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Option<T> {
+        let cmp = &self.cmp;
+        let comparer = |a: &T, b: &T, _: &()| cmp.compare(a, b) == Ordering::Less;
+        self.heap.push(value, &comparer, &())
+    }
+
+    /// Pop the least element off the heap.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let cmp = &self.cmp;
+        let comparer = |a: &T, b: &T, _: &()| cmp.compare(a, b) == Ordering::Less;
+        self.heap.pop(&comparer, &())
+    }
+
+    /// Peek at the least element in the heap.
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.peek()
+    }
+
+    /// Get the number of elements in the heap.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Test if the heap is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
@@ -5,12 +5,14 @@ pub mod input_iter;
 mod iter;
 
 use core::fmt;
+use core::hash::Hash;
 use core::mem;
 use core::ops;
 use core::str::from_utf8;
+use std::collections::HashSet;
 
 use arrayvec::{ArrayString, ArrayVec};
-use bstr::BStr;
+use bstr::{BStr, BString};
 use ringbuffer::ConstGenericRingBuffer;
 
 pub use self::error::{Custom, ErrorKind, IStrError};
@@ -19,6 +21,7 @@ pub use self::iter::Iter;
 type Result<T> = std::result::Result<T, IStrError>;
 pub use self::input_iter::InputIterator;
 use crate::env::Size;
+use crate::grid::OwnedGrid;
 
 pub(crate) const NL: u8 = b'\n';
 
@@ -39,6 +42,30 @@ impl IStr {
         Self { data, index }
     }
 
+    /// Construct an input processor over a copy of `data`, such as an
+    /// in-memory string, without going through the `input!` macro.
+    ///
+    /// The copy is leaked so that it lives for `'static`, which makes this
+    /// suitable for tests and ad-hoc tools but it should not be called in a
+    /// loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let data = String::from("1 2\n3 4\n");
+    /// let mut input = IStr::leak(&data);
+    /// assert_eq!(input.line::<(u32, u32)>()?, (1, 2));
+    /// assert_eq!(input.line::<(u32, u32)>()?, (3, 4));
+    /// assert!(input.is_empty());
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn leak(data: impl AsRef<[u8]>) -> Self {
+        let data: &'static [u8] = Box::leak(Box::from(data.as_ref()));
+        Self::new(data, Size::ZERO)
+    }
+
     /// Access index of input string.
     #[inline]
     pub fn index(&self) -> Size {
@@ -77,6 +104,87 @@ impl IStr {
         true
     }
 
+    /// Test if the remaining input starts with the given literal without
+    /// consuming it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let mut input = IStr::new(b"move 1 from 2", Size::ZERO);
+    /// input.advance(5);
+    ///
+    /// assert!(input.starts_with("1 from"));
+    /// assert!(!input.starts_with("move"));
+    /// assert!(input.starts_with(""));
+    /// assert_eq!(input.as_data(), b"1 from 2");
+    /// ```
+    #[inline]
+    pub fn starts_with(&self, bytes: impl AsRef<[u8]>) -> bool {
+        self.data.starts_with(bytes.as_ref())
+    }
+
+    /// Test if the remaining input ends with the given literal without
+    /// consuming it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let mut input = IStr::new(b"move 1 from 2", Size::ZERO);
+    /// input.advance(5);
+    ///
+    /// assert!(input.ends_with("from 2"));
+    /// assert!(!input.ends_with("move 1"));
+    /// assert!(input.ends_with(""));
+    /// assert_eq!(input.as_data(), b"1 from 2");
+    /// ```
+    #[inline]
+    pub fn ends_with(&self, bytes: impl AsRef<[u8]>) -> bool {
+        self.data.ends_with(bytes.as_ref())
+    }
+
+    /// Consume the given literal, or error with [ErrorKind::Expected] at the
+    /// current position if the input doesn't start with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let mut input = IStr::new(b"a -> b", Size::ZERO);
+    /// assert_eq!(input.next::<W<&str>>()?.0, "a");
+    /// input.expect(" -> ")?;
+    /// assert_eq!(input.next::<W<&str>>()?.0, "b");
+    ///
+    /// let mut input = IStr::new(b"a => b", Size::ZERO);
+    /// input.advance(1);
+    /// let error = input.expect(" -> ").unwrap_err();
+    /// assert_eq!(error.to_string(), "expected ` -> ` (at 1..1)");
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub fn expect<B>(&mut self, bytes: &'static B) -> Result<()>
+    where
+        B: ?Sized + AsRef<[u8]>,
+    {
+        let bytes = bytes.as_ref();
+
+        if !self.eat(bytes) {
+            return Err(IStrError::new(
+                self.index..self.index,
+                ErrorKind::Expected(BStr::new(bytes)),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Peek at the next byte.
     ///
     /// If there are no more bytes, returns `\0`.
@@ -85,12 +193,181 @@ impl IStr {
         self.data.first().copied().unwrap_or(0)
     }
 
+    /// Find the offset of the first occurrence of `byte` in the remaining
+    /// input, without consuming anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let mut input = IStr::new(b"a,b,c", Size::ZERO);
+    /// assert_eq!(input.position(b','), Some(1));
+    /// assert_eq!(input.rposition(b','), Some(3));
+    /// assert_eq!(input.position(b';'), None);
+    ///
+    /// input.advance(2);
+    /// assert_eq!(input.position(b','), Some(1));
+    /// ```
+    #[inline]
+    pub fn position(&self, byte: u8) -> Option<usize> {
+        memchr::memchr(byte, self.data)
+    }
+
+    /// Find the offset of the last occurrence of `byte` in the remaining
+    /// input, without consuming anything.
+    #[inline]
+    pub fn rposition(&self, byte: u8) -> Option<usize> {
+        memchr::memrchr(byte, self.data)
+    }
+
+    /// Count the number of occurrences of `byte` in the remaining input,
+    /// without consuming anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"a,b\nc,d\ne,f\n", Size::ZERO);
+    /// assert_eq!(input.count_byte(b','), 3);
+    /// assert_eq!(input.count_byte(b'\n'), 3);
+    /// assert_eq!(input.count_lines(), 3);
+    ///
+    /// let input = IStr::new(b"a,b\nc,d\ne,f", Size::ZERO);
+    /// assert_eq!(input.count_byte(b'\n'), 2);
+    /// assert_eq!(input.count_lines(), 3);
+    ///
+    /// let input = IStr::new(b"", Size::ZERO);
+    /// assert_eq!(input.count_lines(), 0);
+    /// ```
+    #[inline]
+    pub fn count_byte(&self, byte: u8) -> usize {
+        memchr::memchr_iter(byte, self.data).count()
+    }
+
+    /// Count the number of lines in the remaining input, without consuming
+    /// anything.
+    ///
+    /// Like line parsing, a final line without a trailing newline is counted
+    /// but a trailing newline doesn't produce an extra empty line.
+    #[inline]
+    pub fn count_lines(&self) -> usize {
+        let count = self.count_byte(NL);
+
+        match self.data.last() {
+            Some(&NL) | None => count,
+            Some(..) => count + 1,
+        }
+    }
+
     /// Get remaining binary string of the input.
     #[inline]
     pub fn as_bstr(&self) -> &BStr {
         BStr::new(self.as_data())
     }
 
+    /// Get the remaining input as a string without consuming it.
+    ///
+    /// Errors with [ErrorKind::NotUtf8] spanning the remaining input if it's
+    /// not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    /// use lib::input::ErrorKind;
+    ///
+    /// let mut input = IStr::new(b"x: hello world", Size::ZERO);
+    /// input.advance(3);
+    /// assert_eq!(input.as_str()?, "hello world");
+    ///
+    /// let mut input = IStr::new(b"x: \xff", Size::ZERO);
+    /// input.advance(3);
+    /// let error = input.as_str().unwrap_err();
+    /// assert_eq!(error.to_string(), "not utf-8 (at 3..4)");
+    /// assert!(matches!(error.kind(), ErrorKind::NotUtf8));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> Result<&'static str> {
+        let Ok(string) = from_utf8(self.data) else {
+            let end = self.index.saturating_add(Size::new(self.data.len()));
+            return Err(IStrError::new(self.index..end, ErrorKind::NotUtf8));
+        };
+
+        Ok(string)
+    }
+
+    /// Iterate over each remaining byte in the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let mut input = IStr::new(b"xabc", Size::ZERO);
+    /// input.advance(1);
+    ///
+    /// assert!(input.bytes().eq(*b"abc"));
+    ///
+    /// let indices = input.byte_indices().collect::<Vec<_>>();
+    /// assert_eq!(indices, [(0, b'a'), (1, b'b'), (2, b'c')]);
+    /// assert_eq!(indices.len(), input.len());
+    /// ```
+    #[inline]
+    pub fn bytes(self) -> impl Iterator<Item = u8> {
+        self.data.iter().copied()
+    }
+
+    /// Iterate over each remaining byte in the input together with its
+    /// offset from the current position.
+    #[inline]
+    pub fn byte_indices(self) -> impl Iterator<Item = (usize, u8)> {
+        self.data.iter().copied().enumerate()
+    }
+
+    /// Iterate over each remaining character in the input.
+    ///
+    /// Decoding is lossy, so every invalid UTF-8 sequence is yielded as the
+    /// replacement character `U+FFFD`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"abc", Size::ZERO);
+    /// assert!(input.chars().eq(['a', 'b', 'c']));
+    ///
+    /// let input = IStr::new("aé€".as_bytes(), Size::ZERO);
+    /// assert!(input.chars().eq(['a', 'é', '€']));
+    /// assert_eq!(input.char_indices().collect::<Vec<_>>(), [(0, 'a'), (1, 'é'), (3, '€')]);
+    ///
+    /// let input = IStr::new(b"a\xffb", Size::ZERO);
+    /// assert!(input.chars().eq(['a', '\u{fffd}', 'b']));
+    /// ```
+    #[inline]
+    pub fn chars(self) -> impl Iterator<Item = char> {
+        use bstr::ByteSlice;
+        self.data.chars()
+    }
+
+    /// Iterate over each remaining character in the input together with its
+    /// byte offset from the current position.
+    ///
+    /// Like [IStr::chars], decoding is lossy.
+    #[inline]
+    pub fn char_indices(self) -> impl Iterator<Item = (usize, char)> {
+        use bstr::ByteSlice;
+        self.data.char_indices().map(|(start, _, c)| (start, c))
+    }
+
     /// Cosntruct an iterator over the current input.
     #[inline]
     pub fn iter<T>(self) -> Iter<T> {
@@ -107,6 +384,282 @@ impl IStr {
         self.split_at(move |bytes| Some((finder.find(bytes)?, string.as_ref().len())))
     }
 
+    /// Split the input on the first occurrence of `needle`, returning the
+    /// input before and after it without consuming anything.
+    ///
+    /// This mirrors [`str::split_once`] and returns `None` if `needle` is not
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"a -> b -> c", Size::ZERO);
+    /// let (a, b) = input.split_once_str(" -> ").unwrap();
+    /// assert_eq!(a.as_data(), b"a");
+    /// assert_eq!(b.as_data(), b"b -> c");
+    /// assert_eq!(format!("{:?}", b.index()), "5");
+    ///
+    /// let (a, b) = input.split_once_str("a").unwrap();
+    /// assert!(a.is_empty());
+    /// assert_eq!(b.as_data(), b" -> b -> c");
+    ///
+    /// assert!(input.split_once_str("=>").is_none());
+    /// assert_eq!(input.len(), 11);
+    /// ```
+    pub fn split_once_str(&self, needle: impl AsRef<[u8]>) -> Option<(IStr, IStr)> {
+        let needle = needle.as_ref();
+        let at = memchr::memmem::find(self.data, needle)?;
+        let prefix = self.slice(0..at)?;
+        let suffix = self.slice(at + needle.len()..self.data.len())?;
+        Some((prefix, suffix))
+    }
+
+    /// Split on a byte at most `n - 1` times, yielding at most `n` segments
+    /// where the last one is the unsplit remainder of the input.
+    ///
+    /// This mirrors [`str::splitn`], so nothing is produced if `n` is zero and
+    /// the remainder is produced even if it's empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"a b c d", Size::ZERO);
+    /// assert_eq!(input.splitn(2, b' ').collect_vec::<&str>()?, ["a", "b c d"]);
+    ///
+    /// let input = IStr::new(b"a b c d", Size::ZERO);
+    /// assert_eq!(input.splitn(10, b' ').collect_vec::<&str>()?, ["a", "b", "c", "d"]);
+    ///
+    /// let input = IStr::new(b"a b c d", Size::ZERO);
+    /// assert_eq!(input.splitn(0, b' ').count(), 0);
+    ///
+    /// let input = IStr::new(b"a ", Size::ZERO);
+    /// assert_eq!(input.splitn(2, b' ').collect_vec::<&str>()?, ["a", ""]);
+    ///
+    /// let input = IStr::new(b"a  b", Size::ZERO);
+    /// assert_eq!(input.splitn(10, b' ').collect_vec::<&str>()?, ["a", "", "b"]);
+    ///
+    /// let input = IStr::new(b"", Size::ZERO);
+    /// assert_eq!(input.splitn(2, b' ').collect_vec::<&str>()?, [""]);
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub fn splitn(self, n: usize, byte: u8) -> impl InputIterator {
+        /// Input iterator produced by [IStr::splitn].
+        struct SplitN {
+            input: IStr,
+            n: usize,
+            byte: u8,
+        }
+
+        impl InputIterator for SplitN {
+            #[inline]
+            fn index(&self) -> Size {
+                self.input.index
+            }
+
+            #[inline]
+            fn next_input(&mut self) -> Option<IStr> {
+                self.n = self.n.checked_sub(1)?;
+
+                let found = match self.n {
+                    0 => None,
+                    _ => memchr::memchr(self.byte, self.input.data),
+                };
+
+                let Some(at) = found else {
+                    // Like `str::splitn`, the remainder is produced even if
+                    // it's empty.
+                    self.n = 0;
+                    self.input.index.advance(self.input.data.len());
+                    let data = mem::take(&mut self.input.data);
+                    return Some(IStr::new(data, self.input.index));
+                };
+
+                self.input.split_once_at(|_| Some((at, 1)))
+            }
+        }
+
+        SplitN {
+            input: self,
+            n,
+            byte,
+        }
+    }
+
+    /// Split on a byte, where each segment keeps its trailing delimiter.
+    ///
+    /// This mirrors [`str::split_inclusive`], so a trailing delimiter does
+    /// not produce an empty final segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"a.b.c", Size::ZERO);
+    /// let parts = input.split_inclusive(b'.').iter::<&str>().collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(parts, ["a.", "b.", "c"]);
+    ///
+    /// let input = IStr::new(b"a.b.", Size::ZERO);
+    /// let parts = input.split_inclusive(b'.').iter::<&str>().collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(parts, ["a.", "b."]);
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub fn split_inclusive(self, byte: u8) -> impl InputIterator {
+        self.split_at(move |bytes| Some((memchr::memchr(byte, bytes)? + 1, 0)))
+    }
+
+    /// Split the input into blocks separated by blank lines, such as the
+    /// paragraphs in an input listing one record per paragraph.
+    ///
+    /// This is like splitting on `\n\n`, except that separators with CRLF
+    /// line endings are supported, any number of blank lines count as a
+    /// single separator and trailing blank lines don't produce an empty final
+    /// block. The trailing newline of the last block is stripped.
+    ///
+    /// Every block is sliced out of the original input, so the reported
+    /// [IStr::index] of each block is the offset at which it starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"1\n2\n\n3\n\n4\n5\n\n", Size::ZERO);
+    /// let mut it = input.split_blocks();
+    ///
+    /// let block = it.next::<IStr>()?.unwrap();
+    /// assert_eq!(block.as_data(), b"1\n2");
+    /// assert_eq!(format!("{:?}", block.index()), "0");
+    ///
+    /// let block = it.next::<IStr>()?.unwrap();
+    /// assert_eq!(block.as_data(), b"3");
+    /// assert_eq!(format!("{:?}", block.index()), "5");
+    ///
+    /// let block = it.next::<IStr>()?.unwrap();
+    /// assert_eq!(block.as_data(), b"4\n5");
+    /// assert_eq!(format!("{:?}", block.index()), "8");
+    ///
+    /// assert!(it.next::<IStr>()?.is_none());
+    ///
+    /// let input = IStr::new(b"a\r\nb\r\n\r\nc\r\n", Size::ZERO);
+    /// let blocks = input.split_blocks().iter::<&str>().collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(blocks, ["a\r\nb", "c"]);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn split_blocks(self) -> impl InputIterator {
+        /// Input iterator produced by [IStr::split_blocks].
+        struct SplitBlocks {
+            input: IStr,
+        }
+
+        impl InputIterator for SplitBlocks {
+            #[inline]
+            fn index(&self) -> Size {
+                self.input.index
+            }
+
+            fn next_input(&mut self) -> Option<IStr> {
+                let input = &mut self.input;
+                input.advance(input.find(0, |b| !matches!(b, b'\r' | b'\n')));
+
+                if input.is_empty() {
+                    return None;
+                }
+
+                let mut n = 0;
+
+                let (end, next) = loop {
+                    let Some(at) = memchr::memchr(NL, input.data.get(n..)?) else {
+                        let data = input.data.strip_suffix(&[NL]).unwrap_or(input.data);
+                        let data = data.strip_suffix(b"\r").unwrap_or(data);
+                        break (data.len(), input.data.len());
+                    };
+
+                    let at = n + at;
+                    let rest = input.data.get(at + 1..)?;
+
+                    if rest.starts_with(&[NL]) || rest.starts_with(b"\r\n") {
+                        let data = input.data.get(..at)?;
+                        let data = data.strip_suffix(b"\r").unwrap_or(data);
+                        break (data.len(), at + 1);
+                    }
+
+                    n = at + 1;
+                };
+
+                let block = input.slice(0..end)?;
+                input.advance(next);
+                Some(block)
+            }
+        }
+
+        SplitBlocks { input: self }
+    }
+
+    /// Iterate over lines from last to first.
+    ///
+    /// Like forward line parsing a single trailing newline doesn't produce an
+    /// empty final line, and a trailing `\r` is stripped from each line.
+    ///
+    /// Since every line is sliced out of the original input, the reported
+    /// [IStr::index] of each line is the offset at which it starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"a\nb\nc\n", Size::ZERO);
+    /// let lines = input.lines_rev().map(|l| l.as_data()).collect::<Vec<_>>();
+    /// assert_eq!(lines, [&b"c"[..], b"b", b"a"]);
+    ///
+    /// let input = IStr::new(b"a\r\n\nbc", Size::ZERO);
+    /// let lines = input.lines_rev().collect::<Vec<_>>();
+    /// assert_eq!(lines.iter().map(|l| l.as_data()).collect::<Vec<_>>(), [&b"bc"[..], b"", b"a"]);
+    /// assert_eq!(format!("{:?}", lines[0].index()), "4");
+    ///
+    /// let input = IStr::new(b"", Size::ZERO);
+    /// assert_eq!(input.lines_rev().count(), 0);
+    /// ```
+    pub fn lines_rev(self) -> impl Iterator<Item = IStr> {
+        let mut end = self.data.strip_suffix(&[NL]).unwrap_or(self.data).len();
+        let mut done = self.data.is_empty();
+
+        core::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            let start = match memchr::memrchr(NL, self.data.get(..end)?) {
+                Some(at) => at + 1,
+                None => {
+                    done = true;
+                    0
+                }
+            };
+
+            let mut line = self.slice(start..end)?;
+            end = start.saturating_sub(1);
+
+            if let Some(data) = line.data.strip_suffix(b"\r") {
+                line.data = data;
+            }
+
+            Some(line)
+        })
+    }
+
     /// Split `N` times.
     #[inline]
     fn split_at<'a, F>(self, finder: F) -> impl InputIterator + 'a
@@ -164,26 +717,249 @@ impl IStr {
         T::from_input(self)
     }
 
-    /// Parse the next value as `T`, errors with `Err(IStrError)` if the next
-    /// element is not a valid value of type `T`, returns `Ok(None)` if there is
-    /// no more non-whitespace data to process.
+    /// Parse the next value as `T`, returns `Ok(None)` once the input has been
+    /// fully consumed.
+    ///
+    /// Unlike [IStr::next] this doesn't error at the end of input, and unlike
+    /// [IStr::peek] the value is consumed. No whitespace is skipped before
+    /// testing for the end of input, so any remaining whitespace is handed to
+    /// `T` which decides what to do with it. Word-based parsers such as [W]
+    /// skip leading whitespace on their own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let mut input = IStr::new(b"abc", Size::ZERO);
+    /// let mut values = Vec::new();
+    ///
+    /// while let Some(B(c)) = input.try_next()? {
+    ///     values.push(c);
+    /// }
+    ///
+    /// assert_eq!(values, b"abc");
+    /// assert!(input.is_empty());
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub fn try_next<T>(&mut self) -> Result<Option<T>>
+    where
+        T: FromInput,
+    {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(T::from_input(self)?))
+    }
+
+    /// Parse the whole remaining input as `T`.
+    ///
+    /// Errors with [ErrorKind::ExpectedEof] spanning the trailing data if
+    /// anything but whitespace remains after `T` has been parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    /// use lib::input::ErrorKind;
+    ///
+    /// let input = IStr::new(b"42\n", Size::ZERO);
+    /// assert_eq!(input.parse_all::<u32>()?, 42);
+    ///
+    /// let input = IStr::new(b"42 extra", Size::ZERO);
+    /// let error = input.parse_all::<u32>().unwrap_err();
+    /// assert_eq!(error.to_string(), "expected end of input (at 3..8)");
+    /// assert!(matches!(error.kind(), ErrorKind::ExpectedEof));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub fn parse_all<T>(mut self) -> Result<T>
+    where
+        T: FromInput,
+    {
+        let value = self.next::<T>()?;
+        self.skip_ws();
+
+        if !self.is_empty() {
+            let end = self.index.saturating_add(Size::new(self.data.len()));
+            return Err(IStrError::new(self.index..end, ErrorKind::ExpectedEof));
+        }
+
+        Ok(value)
+    }
+
+    /// Parse the next line as `T`, errors with `Err(IStrError)` if the line is
+    /// not a valid value of type `T`.
+    ///
+    /// A single trailing `\r` is stripped from the line, so inputs with CRLF
+    /// line endings parse the same as ones with plain newlines.
+    ///
+    /// Once the input is exhausted this falls back to [FromInput::from_empty],
+    /// which errors for most types. See [IStr::try_line] for a variant which
+    /// signals the end of input instead.
     #[inline]
     pub fn line<T>(&mut self) -> Result<T>
     where
         T: FromInput,
     {
-        let Some(mut line) = self.split_once(NL) else {
+        let Some(mut line) = self.split_line() else {
             return T::from_empty(self);
         };
 
         line.next::<T>()
     }
 
+    /// Parse the next line as `T`, returns `Ok(None)` once the input has been
+    /// fully consumed.
+    ///
+    /// Unlike [IStr::line] this never consults [FromInput::from_empty], so
+    /// `while let Some(..)` loops terminate cleanly regardless of whether the
+    /// input ends with a newline or not.
+    ///
+    /// Like [IStr::line], a trailing `\r` is stripped from each line so that
+    /// CRLF line endings are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// for data in ["1\n2\n3\n", "1\n2\n3"] {
+    ///     let mut input = IStr::new(data.as_bytes(), Size::ZERO);
+    ///     let mut values = Vec::new();
+    ///
+    ///     while let Some(value) = input.try_line::<u32>()? {
+    ///         values.push(value);
+    ///     }
+    ///
+    ///     assert_eq!(values, [1, 2, 3]);
+    /// }
+    ///
+    /// let mut input = IStr::new(b"a\r\nb\r\n", Size::ZERO);
+    /// assert_eq!(input.try_line::<&str>()?, Some("a"));
+    /// assert_eq!(input.try_line::<&str>()?, Some("b"));
+    /// assert_eq!(input.try_line::<&str>()?, None);
+    ///
+    /// let mut input = IStr::new(b"word\r\n", Size::ZERO);
+    /// assert_eq!(input.next::<W<&str>>()?.0, "word");
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub fn try_line<T>(&mut self) -> Result<Option<T>>
+    where
+        T: FromInput,
+    {
+        let Some(mut line) = self.split_line() else {
+            return Ok(None);
+        };
+
+        Ok(Some(line.next::<T>()?))
+    }
+
+    /// Read the next line and pass it to `f`, only advancing past the line if
+    /// `f` succeeds.
+    ///
+    /// If `f` fails the input is left untouched, so the line can be parsed
+    /// again in a different way. Errors with [ErrorKind::ExpectedLine] if the
+    /// input is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let mut input = IStr::new(b"header\n1\n", Size::ZERO);
+    ///
+    /// assert!(input.with_line(|mut line| line.next::<u32>()).is_err());
+    /// assert_eq!(input.with_line(|line| line.as_str())?, "header");
+    /// assert_eq!(input.with_line(|mut line| line.next::<u32>())?, 1);
+    /// assert!(input.with_line(|line| line.as_str()).is_err());
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    pub fn with_line<T, F>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(IStr) -> Result<T>,
+    {
+        let mut this = *self;
+
+        let Some(line) = this.split_line() else {
+            return Err(IStrError::new(
+                self.index..self.index,
+                ErrorKind::ExpectedLine,
+            ));
+        };
+
+        let value = f(line)?;
+        *self = this;
+        Ok(value)
+    }
+
     /// Shorthand for using [Ws] to scan newlines.
     #[inline]
-    pub fn ws(&mut self) -> Result<usize> {
-        let Ws(n) = self.next::<Ws>()?;
-        Ok(n)
+    pub fn ws(&mut self) -> Result<usize> {
+        let Ws(n) = self.next::<Ws>()?;
+        Ok(n)
+    }
+
+    /// Advance past all leading ASCII whitespace.
+    ///
+    /// Unlike [IStr::ws] this doesn't count the number of newlines consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let mut input = IStr::new(b" \t\n foo ", Size::ZERO);
+    /// input.skip_ws();
+    /// assert_eq!(input.as_data(), b"foo ");
+    /// input.skip_ws();
+    /// assert_eq!(input.as_data(), b"foo ");
+    ///
+    /// let mut input = IStr::new(b" \r\n\n ", Size::ZERO);
+    /// input.skip_ws();
+    /// assert!(input.is_empty());
+    /// ```
+    #[inline]
+    pub fn skip_ws(&mut self) {
+        let n = self.find(0, |b| !b.is_ascii_whitespace());
+        self.advance(n);
+    }
+
+    /// Consume and return the longest prefix of bytes matching `pred`, which
+    /// might be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let mut input = IStr::new(b"abc123-x", Size::ZERO);
+    /// assert_eq!(input.take_while(|b| b.is_ascii_alphabetic()), b"abc");
+    /// assert_eq!(input.take_while(|b| b.is_ascii_alphabetic()), b"");
+    /// assert_eq!(input.take_while(|b| b.is_ascii_digit()), b"123");
+    /// assert_eq!(input.as_data(), b"-x");
+    /// ```
+    #[inline]
+    pub fn take_while(&mut self, pred: impl Fn(u8) -> bool) -> &'static [u8] {
+        let n = self
+            .data
+            .iter()
+            .position(|&b| !pred(b))
+            .unwrap_or(self.data.len());
+
+        let (prefix, _) = self.data.split_at(n);
+        self.advance(n);
+        prefix
     }
 
     /// Try to parse the next word.
@@ -239,6 +1015,19 @@ impl IStr {
         Some(IStr::new(data, index))
     }
 
+    /// Split off the next line, stripping a trailing `\r` so that inputs
+    /// with CRLF line endings are handled transparently.
+    #[inline]
+    fn split_line(&mut self) -> Option<IStr> {
+        let mut line = self.split_once(NL)?;
+
+        if let Some(data) = line.data.strip_suffix(b"\r") {
+            line.data = data;
+        }
+
+        Some(line)
+    }
+
     /// Split once at the given byte or until the end of string, returning the new IStr associated with the split.
     #[inline]
     fn split_once(&mut self, b: u8) -> Option<IStr> {
@@ -430,6 +1219,54 @@ integer!(f64, NotFloat);
 integer!(num::bigint::BigInt, NotInteger);
 integer!(num::bigint::BigUint, NotInteger);
 
+/// Parse a non-zero integer, erroring with [ErrorKind::Zero] if the parsed
+/// value is zero.
+///
+/// # Examples
+///
+/// ```
+/// use core::num::NonZeroU32;
+///
+/// use lib::prelude::*;
+/// use lib::env::Size;
+///
+/// let mut input = IStr::new(b"5", Size::ZERO);
+/// assert_eq!(input.next::<NonZeroU32>()?.get(), 5);
+///
+/// let mut input = IStr::new(b"1 0", Size::ZERO);
+/// let error = input.next::<(NonZeroU32, NonZeroU32)>().unwrap_err();
+/// assert_eq!(error.to_string(), "expected non-zero value (at 2..3)");
+/// # Ok::<_, Error>(())
+/// ```
+macro_rules! non_zero {
+    ($($ty:ident($inner:ty)),* $(,)?) => {
+        $(
+            impl FromInput for core::num::$ty {
+                #[inline]
+                fn from_input(p: &mut IStr) -> Result<Self> {
+                    let s = p.find(0, |b| !b.is_ascii_whitespace());
+                    let start = p.index.saturating_add(Size::new(s));
+                    let n = <$inner>::from_input(p)?;
+
+                    let Some(n) = core::num::$ty::new(n) else {
+                        return Err(IStrError::new(start..p.index, ErrorKind::Zero));
+                    };
+
+                    Ok(n)
+                }
+            }
+        )*
+    };
+}
+
+non_zero! {
+    NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32),
+    NonZeroU64(u64),
+    NonZeroUsize(usize),
+}
+
 impl FromInput for char {
     #[inline]
     fn from_input(p: &mut IStr) -> Result<Self> {
@@ -500,6 +1337,60 @@ impl FromInput for &BStr {
     }
 }
 
+/// Parse the remaining input into an owned string, which must be valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+/// use lib::input::ErrorKind;
+///
+/// let mut input = IStr::new(b"hello world", Size::ZERO);
+/// assert_eq!(input.next::<String>()?, "hello world");
+///
+/// let mut input = IStr::new(b"a\xff", Size::ZERO);
+/// let error = input.next::<String>().unwrap_err();
+/// assert!(matches!(error.kind(), ErrorKind::NotUtf8));
+/// # Ok::<_, Error>(())
+/// ```
+impl FromInput for String {
+    #[inline]
+    fn from_empty(_: &mut IStr) -> Result<Self> {
+        Ok(String::new())
+    }
+
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        Ok(<&str>::from_input(p)?.to_owned())
+    }
+}
+
+/// Parse the remaining input into an owned byte string.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+/// use bstr::BString;
+///
+/// let mut input = IStr::new(b"a\xff", Size::ZERO);
+/// assert_eq!(input.next::<BString>()?, b"a\xff"[..]);
+/// # Ok::<_, Error>(())
+/// ```
+impl FromInput for BString {
+    #[inline]
+    fn from_empty(_: &mut IStr) -> Result<Self> {
+        Ok(BString::default())
+    }
+
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        Ok(BString::from(<&[u8]>::from_input(p)?))
+    }
+}
+
 /// Parse until end of line.
 pub struct Nl<T>(pub T);
 
@@ -518,6 +1409,57 @@ where
     }
 }
 
+/// Assert that the current line has ended, consuming the newline if present.
+///
+/// Trailing spaces, tabs and carriage returns before the newline are allowed
+/// and consumed, but anything else errors with [ErrorKind::ExpectedLine].
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+///
+/// let mut input = IStr::new(b"5\n6  \n7", Size::ZERO);
+/// assert!(matches!(input.next::<(u32, Eol)>()?, (5, Eol)));
+/// assert!(matches!(input.next::<(u32, Eol)>()?, (6, Eol)));
+/// assert!(matches!(input.next::<(u32, Eol)>()?, (7, Eol)));
+/// assert!(input.is_empty());
+///
+/// let mut input = IStr::new(b"5 6", Size::ZERO);
+/// assert!(input.next::<(u32, Eol)>().is_err());
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Eol;
+
+impl FromInput for Eol {
+    #[inline]
+    fn from_empty(_: &mut IStr) -> Result<Self> {
+        Ok(Self)
+    }
+
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        let n = p.find(0, |b| !matches!(b, b' ' | b'\t' | b'\r'));
+
+        match p.data.get(n) {
+            None => {
+                p.advance(n);
+            }
+            Some(&NL) => {
+                p.advance(n + 1);
+            }
+            Some(_) => {
+                let start = p.index.saturating_add(Size::new(n));
+                return Err(IStrError::new(start..start, ErrorKind::ExpectedLine));
+            }
+        }
+
+        Ok(Self)
+    }
+}
+
 /// Consume whitespace and return the number of lines consumed.
 #[derive(Debug)]
 pub struct Ws(pub usize);
@@ -575,7 +1517,17 @@ where
 
         while let Some(element) = Option::<T>::from_input(p)? {
             if output.try_push(element).is_err() {
-                return Err(IStrError::new(index..p.index, ErrorKind::ArrayCapacity(N)));
+                let mut seen = N + 1;
+
+                // Count the remaining elements for a more helpful error.
+                while let Ok(Some(..)) = Option::<T>::from_input(p) {
+                    seen += 1;
+                }
+
+                return Err(IStrError::new(
+                    index..p.index,
+                    ErrorKind::ArrayCapacity(N, seen),
+                ));
             }
         }
 
@@ -615,6 +1567,353 @@ where
     }
 }
 
+/// Parse lines of characters into a grid, until the end of input or an empty
+/// line is reached.
+///
+/// Columns are counted in characters rather than bytes, and every row must
+/// have the same number of characters as the first one.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+///
+/// let mut input = IStr::new("aé#\n.ü.\n".as_bytes(), Size::ZERO);
+/// let grid = input.next::<OwnedGrid<char>>()?;
+/// assert_eq!(grid.rows_len(), 2);
+/// assert_eq!(grid.columns_len(), 3);
+/// assert_eq!(*grid.get(0, 1), 'é');
+/// assert_eq!(*grid.get(1, 2), '.');
+///
+/// let mut input = IStr::new("aé#\n.ü\n".as_bytes(), Size::ZERO);
+/// assert!(input.next::<OwnedGrid<char>>().is_err());
+/// # Ok::<_, Error>(())
+/// ```
+impl FromInput for OwnedGrid<char> {
+    #[inline]
+    fn from_empty(_: &mut IStr) -> Result<Self> {
+        Ok(OwnedGrid::default())
+    }
+
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        let mut data = Vec::new();
+        let mut columns = None;
+
+        while let Some(mut line) = p.split_line() {
+            if line.is_empty() {
+                break;
+            }
+
+            let index = line.index;
+            let start = data.len();
+
+            while let Some(c) = Option::<char>::from_input(&mut line)? {
+                data.push(c);
+            }
+
+            let actual = data.len() - start;
+            let expected = *columns.get_or_insert(actual);
+
+            if actual != expected {
+                return Err(IStrError::new(
+                    index..line.index,
+                    ErrorKind::BadArray(expected, actual),
+                ));
+            }
+        }
+
+        Ok(OwnedGrid::new(data, columns.unwrap_or_default()))
+    }
+}
+
+/// Parse lines of `D`-delimited values into a grid, until the end of input or
+/// an empty line is reached.
+///
+/// If `D` is whitespace, runs of whitespace are treated as a single delimiter
+/// and leading whitespace on each line is ignored. Every row must have the
+/// same number of values as the first one.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+///
+/// let mut input = IStr::new(b"1,2,3\n4,5,6\n", Size::ZERO);
+/// let Tabular(grid) = input.next::<Tabular<',', u32>>()?;
+/// assert_eq!(grid.rows_len(), 2);
+/// assert_eq!(grid.columns_len(), 3);
+/// assert_eq!(grid.as_slice(), &[1, 2, 3, 4, 5, 6]);
+///
+/// let mut input = IStr::new(b"22 13  1\n 8  2 23\n\n3 4", Size::ZERO);
+/// let Tabular(grid) = input.next::<Tabular<' ', u32>>()?;
+/// assert_eq!(grid.as_slice(), &[22, 13, 1, 8, 2, 23]);
+///
+/// let mut input = IStr::new(b"1,2,3\n4,5\n", Size::ZERO);
+/// assert!(input.next::<Tabular<',', u32>>().is_err());
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tabular<const D: char, T>(pub OwnedGrid<T>);
+
+impl<const D: char, T> FromInput for Tabular<D, T>
+where
+    T: FromInput,
+{
+    #[inline]
+    fn from_empty(_: &mut IStr) -> Result<Self> {
+        Ok(Self(OwnedGrid::default()))
+    }
+
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        let mut string = [0u8; 4];
+        let string = D.encode_utf8(&mut string);
+
+        let mut data = Vec::new();
+        let mut columns = None;
+
+        while let Some(mut line) = p.split_line() {
+            if line.is_empty() {
+                break;
+            }
+
+            let index = line.index;
+            let start = data.len();
+
+            if D.is_ascii_whitespace() {
+                while let Some((_, value)) = line.try_next_word::<T>()? {
+                    data.push(value);
+                }
+            } else {
+                let mut it = line.split(string);
+
+                while let Some(value) = it.next::<T>()? {
+                    data.push(value);
+                }
+            }
+
+            let actual = data.len() - start;
+            let expected = *columns.get_or_insert(actual);
+
+            if actual != expected {
+                return Err(IStrError::new(
+                    index..p.index,
+                    ErrorKind::BadArray(expected, actual),
+                ));
+            }
+        }
+
+        Ok(Self(OwnedGrid::new(data, columns.unwrap_or_default())))
+    }
+}
+
+/// Parse a row of `N` characters into a mask, where the `ON` character
+/// (default `#`) is `true` and every other character is `false`.
+///
+/// Leading whitespace is skipped, and it's an error if the row ends before
+/// `N` characters have been read.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+/// use lib::input::ErrorKind;
+///
+/// let mut input = IStr::new(b"#.#.\n.##.\n", Size::ZERO);
+/// let Mask(a) = input.line::<Mask<4>>()?;
+/// let Mask(b) = input.line::<Mask<4>>()?;
+/// assert_eq!(a, [true, false, true, false]);
+/// assert_eq!(b, [false, true, true, false]);
+///
+/// let mut input = IStr::new(b"1001", Size::ZERO);
+/// let Mask(bits) = input.next::<Mask<4, '1'>>()?;
+/// assert_eq!(bits, [true, false, false, true]);
+///
+/// let mut input = IStr::new(b"#.\n#.", Size::ZERO);
+/// let error = input.next::<Mask<4>>().unwrap_err();
+/// assert!(matches!(error.kind(), ErrorKind::BadArray(4, 2)));
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mask<const N: usize, const ON: char = '#'>(pub [bool; N]);
+
+impl<const N: usize, const ON: char> FromInput for Mask<N, ON> {
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        p.skip_ws();
+
+        let index = p.index;
+        let mut mask = [false; N];
+
+        for (n, value) in mask.iter_mut().enumerate() {
+            if p.is_empty() || matches!(p.at(), b'\n' | b'\r') {
+                return Err(IStrError::new(index..p.index, ErrorKind::BadArray(N, n)));
+            }
+
+            *value = char::from_input(p)? == ON;
+        }
+
+        Ok(Self(mask))
+    }
+}
+
+/// Parse a row of characters into a bit set of `N` 64-bit words, where the
+/// position of every `ON` character (default `#`) is set.
+///
+/// Leading whitespace is skipped and the row ends at the next whitespace. It's
+/// an error if the row has more characters than the set has bits.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+/// use lib::input::ErrorKind;
+///
+/// let mut input = IStr::new(b"#..#.#\n", Size::ZERO);
+/// let BitRow(set) = input.line::<BitRow<1>>()?;
+/// assert!(set.test_bit(0));
+/// assert!(!set.test_bit(1));
+/// assert!(set.test_bit(3));
+/// assert!(set.test_bit(5));
+/// assert_eq!(set.count_ones(), 3);
+///
+/// let mut input = IStr::new(b"o.o", Size::ZERO);
+/// let BitRow(set) = input.next::<BitRow<1, 'o'>>()?;
+/// assert_eq!(set.count_ones(), 2);
+///
+/// let row = "#".repeat(65);
+/// let mut input = IStr::leak(&row);
+/// let error = input.next::<BitRow<1>>().unwrap_err();
+/// assert!(matches!(error.kind(), ErrorKind::ArrayCapacity(64, 65)));
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitRow<const N: usize, const ON: char = '#'>(pub bittle::Set<[u64; N]>);
+
+impl<const N: usize, const ON: char> FromInput for BitRow<N, ON> {
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        use bittle::BitsMut;
+
+        p.skip_ws();
+
+        let index = p.index;
+        let mut set = bittle::Set::new([0u64; N]);
+        let mut n = 0;
+
+        while !p.is_empty() && !p.at().is_ascii_whitespace() {
+            if char::from_input(p)? == ON && n < N * 64 {
+                set.set_bit(n as u32);
+            }
+
+            n += 1;
+        }
+
+        if n > N * 64 {
+            return Err(IStrError::new(
+                index..p.index,
+                ErrorKind::ArrayCapacity(N * 64, n),
+            ));
+        }
+
+        Ok(Self(set))
+    }
+}
+
+/// Parse exactly `R` lines of exactly `C` whitespace-separated values into a
+/// nested array.
+///
+/// Leading whitespace, such as the empty line separating two boards, is
+/// skipped before the first row. Unlike parsing `[[T; C]; R]` directly, each
+/// row must be on its own line.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+/// use lib::input::ErrorKind;
+///
+/// let mut input = IStr::new(b"1 2 3\n4 5 6\n\n7 8 9\n1 2 3\n", Size::ZERO);
+/// let Lines(a) = input.next::<Lines<2, 3, u32>>()?;
+/// let Lines(b) = input.next::<Lines<2, 3, u32>>()?;
+/// assert_eq!(a, [[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(b, [[7, 8, 9], [1, 2, 3]]);
+///
+/// let mut input = IStr::new(b"1 2 3\n4 5\n6", Size::ZERO);
+/// let error = input.next::<Lines<2, 3, u32>>().unwrap_err();
+/// assert!(matches!(error.kind(), ErrorKind::BadArray(3, 2)));
+///
+/// let mut input = IStr::new(b"1 2 3\n", Size::ZERO);
+/// let error = input.next::<Lines<2, 3, u32>>().unwrap_err();
+/// assert!(matches!(error.kind(), ErrorKind::BadArray(2, 1)));
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lines<const R: usize, const C: usize, T>(pub [[T; C]; R]);
+
+impl<const R: usize, const C: usize, T> FromInput for Lines<R, C, T>
+where
+    T: FromInput,
+{
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        p.skip_ws();
+
+        let index = p.index;
+        let mut rows = ArrayVec::<[T; C], R>::new();
+
+        while rows.remaining_capacity() > 0 {
+            let Some(mut line) = p.split_line() else {
+                return Err(IStrError::new(
+                    index..p.index,
+                    ErrorKind::BadArray(R, rows.len()),
+                ));
+            };
+
+            let start = line.index;
+            let mut row = ArrayVec::<T, C>::new();
+            let mut count = 0;
+
+            loop {
+                line.skip_ws();
+
+                if line.is_empty() {
+                    break;
+                }
+
+                let value = line.next::<T>()?;
+                count += 1;
+                // Excess values are only counted for the error.
+                let _ = row.try_push(value);
+            }
+
+            match row.into_inner() {
+                Ok(row) if count == C => rows.push(row),
+                _ => {
+                    return Err(IStrError::new(
+                        start..line.index,
+                        ErrorKind::BadArray(C, count),
+                    ));
+                }
+            }
+        }
+
+        match rows.into_inner() {
+            Ok(rows) => Ok(Self(rows)),
+            Err(rows) => Err(IStrError::new(
+                index..p.index,
+                ErrorKind::BadArray(R, rows.len()),
+            )),
+        }
+    }
+}
+
 /// Split once on byte `D`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Split<const D0: char, T>(pub T);
@@ -637,6 +1936,40 @@ where
     }
 }
 
+/// Parse `T` from values delimited by `D`, such as tuples or arrays.
+///
+/// This is the preferred way to parse delimited tuples, and behaves exactly
+/// like [Split].
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+///
+/// let mut input = IStr::new(b"1,2,3", Size::ZERO);
+/// let Delim((a, b, c)) = input.next::<Delim<',', (u32, u32, u32)>>()?;
+/// assert_eq!((a, b, c), (1, 2, 3));
+///
+/// let mut input = IStr::new(b"1-2", Size::ZERO);
+/// let Delim(values) = input.next::<Delim<'-', [u32; 2]>>()?;
+/// assert_eq!(values, [1, 2]);
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Delim<const D: char, T>(pub T);
+
+impl<const D: char, T> FromInput for Delim<D, T>
+where
+    T: FromInputIter,
+{
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        let Split(out) = p.next::<Split<D, T>>()?;
+        Ok(Self(out))
+    }
+}
+
 /// Split on pair of characters.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Split2<const D0: char, const D1: char, T>(pub T);
@@ -675,6 +2008,34 @@ where
     }
 }
 
+/// Split and return an inclusive range.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+///
+/// let mut input = IStr::new(b"2-4", Size::ZERO);
+/// let RangeIncl(range) = input.next::<RangeIncl<'-', u32>>()?;
+/// assert_eq!(range, 2..=4);
+/// assert!(range.contains(&4));
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeIncl<const D: char, T>(pub ops::RangeInclusive<T>);
+
+impl<const D: char, T> FromInput for RangeIncl<D, T>
+where
+    T: FromInput,
+{
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        let Split([a, b]) = Split::<D, [T; 2]>::from_input(p)?;
+        Ok(Self(a..=b))
+    }
+}
+
 impl<const N: usize, T> FromInputIter for [T; N]
 where
     T: FromInput,
@@ -684,30 +2045,30 @@ where
     where
         I: InputIterator,
     {
-        let index = it.index();
-        let mut array = ArrayVec::<T, N>::new();
-
-        while array.remaining_capacity() > 0 {
-            let Some(value) = it.next()? else {
-                return Err(IStrError::new(
-                    index..it.index(),
-                    ErrorKind::BadArray(N, array.len()),
-                ));
-            };
-
-            array.push(value);
-        }
-
-        match array.into_inner() {
-            Ok(array) => Ok(Some(array)),
-            Err(array) => Err(IStrError::new(
-                index..it.index(),
-                ErrorKind::BadArray(N, array.len()),
-            )),
-        }
+        Ok(Some(it.collect_array()?))
     }
 }
 
+/// Collect every remaining input into a fixed capacity array.
+///
+/// Errors with [ErrorKind::ArrayCapacity] if the capacity is exceeded, which
+/// reports the total number of available elements.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+///
+/// let mut input = IStr::new(b"1,2,3", Size::ZERO);
+/// let Split(values) = input.next::<Split<',', ArrayVec<u32, 4>>>()?;
+/// assert_eq!(values.as_slice(), [1, 2, 3]);
+///
+/// let mut input = IStr::new(b"1,2,3,4,5,6", Size::ZERO);
+/// let error = input.next::<Split<',', ArrayVec<u32, 4>>>().unwrap_err();
+/// assert_eq!(error.to_string(), "array out of capacity (4), but got 6 elements (at 0..11)");
+/// # Ok::<_, Error>(())
+/// ```
 impl<const N: usize, T> FromInputIter for ArrayVec<T, N>
 where
     T: FromInput,
@@ -722,9 +2083,11 @@ where
 
         while let Some(value) = it.next::<T>()? {
             if array.try_push(value).is_err() {
+                let seen = N + 1 + InputIterator::count(&mut *it);
+
                 return Err(IStrError::new(
                     index..it.index(),
-                    ErrorKind::ArrayCapacity(N),
+                    ErrorKind::ArrayCapacity(N, seen),
                 ));
             }
         }
@@ -749,9 +2112,11 @@ where
 
         while let Some(value) = it.next()? {
             if array.is_full() {
+                let seen = N + 1 + InputIterator::count(&mut *it);
+
                 return Err(IStrError::new(
                     index..it.index(),
-                    ErrorKind::RingbufCapacity(N),
+                    ErrorKind::RingbufCapacity(N, seen),
                 ));
             }
 
@@ -762,6 +2127,64 @@ where
     }
 }
 
+/// Collect every remaining input.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use lib::prelude::*;
+/// use lib::env::Size;
+///
+/// let mut input = IStr::new(b"1,2,3", Size::ZERO);
+/// let Split(values) = input.next::<Split<',', Vec<u32>>>()?;
+/// assert_eq!(values, [1, 2, 3]);
+///
+/// let mut input = IStr::new(b"1,1,2", Size::ZERO);
+/// let Split(values) = input.next::<Split<',', HashSet<u32>>>()?;
+/// assert_eq!(values, HashSet::from([1, 2]));
+/// # Ok::<_, Error>(())
+/// ```
+impl<T> FromInputIter for Vec<T>
+where
+    T: FromInput,
+{
+    #[inline]
+    fn from_input_iter<I>(it: &mut I) -> Result<Option<Self>>
+    where
+        I: InputIterator,
+    {
+        let mut output = Vec::new();
+
+        while let Some(value) = it.next()? {
+            output.push(value);
+        }
+
+        Ok(Some(output))
+    }
+}
+
+/// Collect every remaining input, discarding duplicates.
+impl<T> FromInputIter for HashSet<T>
+where
+    T: Eq + Hash + FromInput,
+{
+    #[inline]
+    fn from_input_iter<I>(it: &mut I) -> Result<Option<Self>>
+    where
+        I: InputIterator,
+    {
+        let mut output = HashSet::new();
+
+        while let Some(value) = it.next()? {
+            output.insert(value);
+        }
+
+        Ok(Some(output))
+    }
+}
+
 #[non_exhaustive]
 pub struct Skip;
 
@@ -777,6 +2200,42 @@ impl FromInput for Skip {
     }
 }
 
+/// The unit type parses successfully without consuming any input, which is
+/// useful as a structural placeholder inside of tuples.
+///
+/// Unlike [Skip], which marks a value that wrappers such as [W] should parse
+/// and discard, `()` never looks at the input at all.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+///
+/// let mut input = IStr::new(b"42", Size::ZERO);
+/// let ((), n) = input.next::<((), u32)>()?;
+/// assert_eq!(n, 42);
+///
+/// let mut input = IStr::new(b"1 2", Size::ZERO);
+/// let (a, (), b) = input.next::<(u32, (), u32)>()?;
+/// assert_eq!((a, b), (1, 2));
+///
+/// let mut input = IStr::new(b"", Size::ZERO);
+/// input.next::<()>()?;
+/// # Ok::<_, Error>(())
+/// ```
+impl FromInput for () {
+    #[inline]
+    fn from_empty(_: &mut IStr) -> Result<Self> {
+        Ok(())
+    }
+
+    #[inline]
+    fn from_input(_: &mut IStr) -> Result<Self> {
+        Ok(())
+    }
+}
+
 /// Parse a word of input, which parses until we reach a whitespace or control character.
 pub struct W<T = Skip>(pub T);
 
@@ -842,3 +2301,39 @@ impl FromInput for B {
         Ok(Self(b))
     }
 }
+
+/// Read exactly `N` raw bytes, without skipping any whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+/// use lib::input::ErrorKind;
+///
+/// let mut input = IStr::new(b"AB C", Size::ZERO);
+/// let Bytes(bytes) = input.next::<Bytes<2>>()?;
+/// assert_eq!(&bytes, b"AB");
+/// assert_eq!(input.as_data(), b" C");
+///
+/// let mut input = IStr::new(b"A", Size::ZERO);
+/// let error = input.next::<Bytes<2>>().unwrap_err();
+/// assert_eq!(error.to_string(), "unexpected eof (at 0..1)");
+/// assert!(matches!(error.kind(), ErrorKind::UnexpectedEof));
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bytes<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> FromInput for Bytes<N> {
+    #[inline]
+    fn from_input(p: &mut IStr) -> Result<Self> {
+        let Some(&bytes) = p.data.first_chunk::<N>() else {
+            let end = p.index.saturating_add(Size::new(p.data.len()));
+            return Err(IStrError::new(p.index..end, ErrorKind::UnexpectedEof));
+        };
+
+        p.advance(N);
+        Ok(Self(bytes))
+    }
+}
@@ -85,12 +85,20 @@ pub enum ErrorKind {
     BadArray(usize, usize),
     ExpectedChar,
     ExpectedLine,
+    Expected(&'static BStr),
     ExpectedTuple(usize),
     NotByteMuck,
     UnexpectedEof,
+    /// Expected the end of input, but there was trailing data.
+    ExpectedEof,
+    Zero,
     StringCapacity(usize),
-    ArrayCapacity(usize),
-    RingbufCapacity(usize),
+    /// An array ran out of capacity, with its capacity and the number of
+    /// elements that were available.
+    ArrayCapacity(usize, usize),
+    /// A ring buffer ran out of capacity, with its capacity and the number of
+    /// elements that were available.
+    RingbufCapacity(usize, usize),
     ParseIntError(ParseIntError),
     ParseBigIntError(ParseBigIntError),
     Custom(Custom),
@@ -108,12 +116,22 @@ impl fmt::Display for ErrorKind {
             }
             ErrorKind::ExpectedChar => write!(f, "exptected charater"),
             ErrorKind::ExpectedLine => write!(f, "expected line"),
+            ErrorKind::Expected(bytes) => write!(f, "expected `{bytes}`"),
             ErrorKind::UnexpectedEof => write!(f, "unexpected eof"),
+            ErrorKind::ExpectedEof => write!(f, "expected end of input"),
             ErrorKind::ExpectedTuple(n) => write!(f, "expected tuple of length `{n}`"),
+            ErrorKind::Zero => write!(f, "expected non-zero value"),
             ErrorKind::NotByteMuck => write!(f, "not a valid number muck"),
             ErrorKind::StringCapacity(cap) => write!(f, "string out of capacity ({cap})"),
-            ErrorKind::ArrayCapacity(cap) => write!(f, "array out of capacity ({cap})"),
-            ErrorKind::RingbufCapacity(cap) => write!(f, "ringbuf out of capacity ({cap})"),
+            ErrorKind::ArrayCapacity(cap, seen) => {
+                write!(f, "array out of capacity ({cap}), but got {seen} elements")
+            }
+            ErrorKind::RingbufCapacity(cap, seen) => {
+                write!(
+                    f,
+                    "ringbuf out of capacity ({cap}), but got {seen} elements"
+                )
+            }
             ErrorKind::ParseIntError(e) => write!(f, "{e}"),
             ErrorKind::ParseBigIntError(e) => write!(f, "{e}"),
             ErrorKind::Custom(c) => write!(f, "custom: {c}"),
@@ -1,7 +1,10 @@
+use core::iter::{Product, Sum};
 use core::marker::PhantomData;
 
+use arrayvec::ArrayVec;
+
 use crate::env::Size;
-use crate::input::{IStr, Result};
+use crate::input::{ErrorKind, IStr, IStrError, Result};
 
 use super::FromInput;
 
@@ -18,6 +21,106 @@ pub trait InputIterator {
         Take { iter: self, n }
     }
 
+    /// Skip the first `n` inputs of the iterator.
+    ///
+    /// If there are fewer than `n` inputs, nothing is produced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"1,2,3,4,5", Size::ZERO);
+    /// assert_eq!(input.split(",").skip(2).collect_vec::<u32>()?, [3, 4, 5]);
+    ///
+    /// let input = IStr::new(b"1,2,3,4,5", Size::ZERO);
+    /// assert_eq!(input.split(",").skip(10).count(), 0);
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn skip(self, n: usize) -> SkipIter<Self>
+    where
+        Self: Sized,
+    {
+        SkipIter { iter: self, n }
+    }
+
+    /// Parse each input as `T` and iterate over overlapping windows of the
+    /// last `N` parsed values.
+    ///
+    /// Nothing is produced if there are fewer than `N` inputs. Iteration stops
+    /// after the first error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"1 2 3 4", Size::ZERO);
+    /// let windows = input.split(" ").parsed_windows::<2, u32>().collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(windows, [[1, 2], [2, 3], [3, 4]]);
+    ///
+    /// let input = IStr::new(b"1 2", Size::ZERO);
+    /// assert_eq!(input.split(" ").parsed_windows::<3, u32>().count(), 0);
+    ///
+    /// let input = IStr::new(b"1 2 x 4", Size::ZERO);
+    /// let mut it = input.split(" ").parsed_windows::<2, u32>();
+    /// assert_eq!(it.next().transpose()?, Some([1, 2]));
+    /// assert!(it.next().is_some_and(|w| w.is_err()));
+    /// assert!(it.next().is_none());
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn parsed_windows<const N: usize, T>(self) -> ParsedWindows<Self, T, N>
+    where
+        Self: Sized,
+        T: FromInput + Copy,
+    {
+        assert!(N != 0, "window size must be non-zero");
+
+        ParsedWindows {
+            iter: self,
+            window: ArrayVec::new(),
+            done: false,
+        }
+    }
+
+    /// Construct an input iterator which can peek at the next input without
+    /// consuming it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"header,1,2", Size::ZERO);
+    /// let mut it = input.split(",").peekable();
+    ///
+    /// assert_eq!(it.peek().map(|s| s.as_data()), Some(&b"header"[..]));
+    /// assert_eq!(it.peek().map(|s| s.as_data()), Some(&b"header"[..]));
+    ///
+    /// assert_eq!(it.next::<&str>()?, Some("header"));
+    /// assert_eq!(it.next::<u32>()?, Some(1));
+    /// assert_eq!(it.next::<u32>()?, Some(2));
+    /// assert!(it.peek().is_none());
+    /// assert_eq!(it.next::<u32>()?, None);
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn peekable(self) -> Peekable<Self>
+    where
+        Self: Sized,
+    {
+        Peekable {
+            iter: self,
+            peeked: None,
+        }
+    }
+
     /// Next value as type `T`.
     fn next<T>(&mut self) -> Result<Option<T>>
     where
@@ -31,6 +134,266 @@ pub trait InputIterator {
         Ok(Some(value))
     }
 
+    /// Collect exactly `N` values of type `T` into an array.
+    ///
+    /// Errors with [ErrorKind::BadArray] if the iterator runs out of inputs
+    /// before the array has been filled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    /// use lib::input::ErrorKind;
+    ///
+    /// let input = IStr::new(b"1 2 3", Size::ZERO);
+    /// assert_eq!(input.split(" ").collect_array::<3, u32>()?, [1, 2, 3]);
+    ///
+    /// let input = IStr::new(b"1 2", Size::ZERO);
+    /// let error = input.split(" ").collect_array::<3, u32>().unwrap_err();
+    /// assert!(matches!(error.kind(), ErrorKind::BadArray(3, 2)));
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn collect_array<const N: usize, T>(&mut self) -> Result<[T; N]>
+    where
+        T: FromInput,
+    {
+        let index = self.index();
+        let mut array = ArrayVec::<T, N>::new();
+
+        while array.remaining_capacity() > 0 {
+            let Some(value) = self.next()? else {
+                break;
+            };
+
+            array.push(value);
+        }
+
+        match array.into_inner() {
+            Ok(array) => Ok(array),
+            Err(array) => Err(IStrError::new(
+                index..self.index(),
+                ErrorKind::BadArray(N, array.len()),
+            )),
+        }
+    }
+
+    /// Collect every remaining input as `T` into a vector, stopping at the
+    /// first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"1,2,3", Size::ZERO);
+    /// assert_eq!(input.split(",").collect_vec::<u32>()?, [1, 2, 3]);
+    ///
+    /// let input = IStr::new(b"1,x,3", Size::ZERO);
+    /// assert!(input.split(",").collect_vec::<u32>().is_err());
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    fn collect_vec<T>(self) -> Result<Vec<T>>
+    where
+        Self: Sized,
+        T: FromInput,
+    {
+        let mut output = Vec::new();
+        self.collect_into(&mut output)?;
+        Ok(output)
+    }
+
+    /// Parse every remaining input as `T` and extend `output` with them,
+    /// stopping at the first error.
+    ///
+    /// Values parsed before an error has been encountered are kept in
+    /// `output`. Since containers like [Vec] can be extended with both values
+    /// and references, `T` might have to be specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let mut values = vec![0u32];
+    ///
+    /// let input = IStr::new(b"1,2,3", Size::ZERO);
+    /// input.split(",").collect_into::<u32, _>(&mut values)?;
+    /// assert_eq!(values, [0, 1, 2, 3]);
+    ///
+    /// let input = IStr::new(b"4,x", Size::ZERO);
+    /// assert!(input.split(",").collect_into::<u32, _>(&mut values).is_err());
+    /// assert_eq!(values, [0, 1, 2, 3, 4]);
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    fn collect_into<T, C>(mut self, output: &mut C) -> Result<()>
+    where
+        Self: Sized,
+        T: FromInput,
+        C: Extend<T>,
+    {
+        while let Some(value) = self.next()? {
+            output.extend([value]);
+        }
+
+        Ok(())
+    }
+
+    /// Count the number of remaining inputs, consuming the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"a,b,c", Size::ZERO);
+    /// assert_eq!(input.split(",").count(), 3);
+    /// ```
+    #[inline]
+    fn count(mut self) -> usize
+    where
+        Self: Sized,
+    {
+        let mut count = 0;
+
+        while self.next_input().is_some() {
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Fold every remaining input into an accumulator, consuming the
+    /// iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"a,bb,ccc", Size::ZERO);
+    /// let total = input.split(",").fold(0, |n, value| n + value.len());
+    /// assert_eq!(total, 6);
+    /// ```
+    #[inline]
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, IStr) -> B,
+    {
+        let mut acc = init;
+
+        while let Some(value) = self.next_input() {
+            acc = f(acc, value);
+        }
+
+        acc
+    }
+
+    /// Parse every remaining input as `T` and sum them, stopping at the first
+    /// error.
+    ///
+    /// An empty iterator sums to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"1,2,3", Size::ZERO);
+    /// assert_eq!(input.split(",").sum::<u32>()?, 6);
+    ///
+    /// let input = IStr::new(b"1,2,3", Size::ZERO);
+    /// assert_eq!(input.split(",").take(0).sum::<u32>()?, 0);
+    ///
+    /// let input = IStr::new(b"1,x,3", Size::ZERO);
+    /// assert!(input.split(",").sum::<u32>().is_err());
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    fn sum<T>(self) -> Result<T>
+    where
+        Self: Sized,
+        T: FromInput + Sum,
+    {
+        self.iter::<T>().sum()
+    }
+
+    /// Parse every remaining input as `T` and multiply them, stopping at the
+    /// first error.
+    ///
+    /// An empty iterator has a product of one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"2,3,4", Size::ZERO);
+    /// assert_eq!(input.split(",").product::<u64>()?, 24);
+    ///
+    /// let input = IStr::new(b"2,3,4", Size::ZERO);
+    /// assert_eq!(input.split(",").take(0).product::<u64>()?, 1);
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    fn product<T>(self) -> Result<T>
+    where
+        Self: Sized,
+        T: FromInput + Product,
+    {
+        self.iter::<T>().product()
+    }
+
+    /// Parse each remaining input as `T` and call `f` with it, stopping at
+    /// the first error from either parsing or the callback.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    /// use lib::env::Size;
+    ///
+    /// let input = IStr::new(b"1,2,3", Size::ZERO);
+    /// let mut sum = 0;
+    /// input.split(",").try_for_each(|n: u32| {
+    ///     sum += n;
+    ///     Ok::<_, Error>(())
+    /// })?;
+    /// assert_eq!(sum, 6);
+    ///
+    /// let input = IStr::new(b"1,x,3", Size::ZERO);
+    /// let mut seen = Vec::new();
+    /// let result = input.split(",").try_for_each(|n: u32| {
+    ///     seen.push(n);
+    ///     Ok::<_, Error>(())
+    /// });
+    /// assert!(result.is_err());
+    /// assert_eq!(seen, [1]);
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[inline]
+    fn try_for_each<T, F, E>(&mut self, mut f: F) -> core::result::Result<(), E>
+    where
+        T: FromInput,
+        F: FnMut(T) -> core::result::Result<(), E>,
+        E: From<IStrError>,
+    {
+        while let Some(value) = self.next()? {
+            f(value)?;
+        }
+
+        Ok(())
+    }
+
     #[inline]
     fn iter<T>(self) -> Iter<Self, T>
     where
@@ -73,6 +436,120 @@ where
     }
 }
 
+/// See [InputIterator::skip].
+pub struct SkipIter<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I> InputIterator for SkipIter<I>
+where
+    I: InputIterator,
+{
+    #[inline]
+    fn index(&self) -> Size {
+        self.iter.index()
+    }
+
+    #[inline]
+    fn next_input(&mut self) -> Option<IStr> {
+        while self.n > 0 {
+            self.n -= 1;
+            self.iter.next_input()?;
+        }
+
+        self.iter.next_input()
+    }
+}
+
+/// See [InputIterator::parsed_windows].
+pub struct ParsedWindows<I, T, const N: usize> {
+    iter: I,
+    window: ArrayVec<T, N>,
+    done: bool,
+}
+
+impl<I, T, const N: usize> Iterator for ParsedWindows<I, T, N>
+where
+    I: InputIterator,
+    T: FromInput + Copy,
+{
+    type Item = Result<[T; N]>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if self.window.is_full() {
+            self.window.remove(0);
+        }
+
+        while !self.window.is_full() {
+            match self.iter.next() {
+                Ok(Some(value)) => {
+                    self.window.push(value);
+                }
+                Ok(None) => {
+                    self.done = true;
+                    return None;
+                }
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+
+        <[T; N]>::try_from(self.window.as_slice()).ok().map(Ok)
+    }
+}
+
+/// See [InputIterator::peekable].
+pub struct Peekable<I> {
+    iter: I,
+    /// The buffered input together with the index before it was produced.
+    peeked: Option<(Size, Option<IStr>)>,
+}
+
+impl<I> Peekable<I>
+where
+    I: InputIterator,
+{
+    /// Peek at the next input without consuming it.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&IStr> {
+        let iter = &mut self.iter;
+
+        self.peeked
+            .get_or_insert_with(|| (iter.index(), iter.next_input()))
+            .1
+            .as_ref()
+    }
+}
+
+impl<I> InputIterator for Peekable<I>
+where
+    I: InputIterator,
+{
+    #[inline]
+    fn index(&self) -> Size {
+        match &self.peeked {
+            Some((index, _)) => *index,
+            None => self.iter.index(),
+        }
+    }
+
+    #[inline]
+    fn next_input(&mut self) -> Option<IStr> {
+        match self.peeked.take() {
+            Some((_, value)) => value,
+            None => self.iter.next_input(),
+        }
+    }
+}
+
 impl<I> InputIterator for &mut I
 where
     I: InputIterator,
@@ -5,6 +5,10 @@ mod macros;
 mod arena;
 mod ext;
 mod grid;
+pub mod heap;
+pub mod math;
+pub mod matrix;
+pub mod runner;
 
 #[cfg(prod)]
 #[path = "env/prod.rs"]
@@ -42,15 +46,21 @@ macro_rules! ensure {
 pub mod prelude {
     //! Helper prelude with useful imports.
     pub use crate::input::{
-        Digits, IStr, InputIterator, Nl, NonEmpty, Range, Skip, Split, Split2, Ws, B, W,
+        BitRow, Bytes, Delim, Digits, Eol, IStr, InputIterator, Lines, Mask, Nl, NonEmpty, Range,
+        RangeIncl, Skip, Split, Split2, Tabular, Ws, B, W,
     };
     pub use anyhow::{self, bail, Context, Error, Result};
     pub type ArrayVec<T, const N: usize = 16> = arrayvec::ArrayVec<T, N>;
     pub type ArrayString<const N: usize = 16> = arrayvec::ArrayString<N>;
     pub use crate::arena::{AllocIter, Arena, ArenaAllocError, ArenaWriteSliceOutOfBounds};
+    pub use crate::cli::Approx;
     pub use crate::ensure;
-    pub use crate::ext::SliceExt;
-    pub use crate::grid::{Grid, GridExt, GridMut, GridSliceMut, GridSliceRef};
+    pub use crate::ext::{first_distinct_window, SliceExt};
+    pub use crate::grid::{
+        Grid, GridBytes, GridExt, GridMut, GridSliceMut, GridSliceRef, OwnedGrid, RaggedRows,
+    };
+    pub use crate::heap::MinHeap;
+    pub use crate::matrix::Matrix;
     pub use bittle::{set as bits, Bits, BitsMut, BitsOwned, Set};
     pub use bstr::{BStr, ByteSlice};
     pub use fixed_heap::FixedHeap;
@@ -1,4 +1,37 @@
 /// Helper macro to build an input processor.
+///
+/// Besides wrapping a closure, an enum can be declared by mapping characters
+/// to variants. Its `FromInput` implementation reads a single character, and
+/// errors with [`ErrorKind::Custom`] containing the character if it isn't
+/// one of the listed ones.
+///
+/// [`ErrorKind::Custom`]: crate::input::ErrorKind::Custom
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+/// use lib::input::{Custom, ErrorKind};
+///
+/// lib::from_input! {
+///     enum Move {
+///         'R' => Right,
+///         'U' => Up,
+///         'L' => Left,
+///         'D' => Down,
+///     }
+/// }
+///
+/// let mut input = IStr::new(b"U 4", Size::ZERO);
+/// let (m, n) = input.next::<(Move, u32)>()?;
+/// assert_eq!((m, n), (Move::Up, 4));
+///
+/// let mut input = IStr::new(b"X 4", Size::ZERO);
+/// let error = input.next::<(Move, u32)>().unwrap_err();
+/// assert!(matches!(error.kind(), ErrorKind::Custom(Custom::Char('X'))));
+/// # Ok::<_, Error>(())
+/// ```
 #[macro_export]
 macro_rules! from_input {
     (|$($value:ident)? $(($($pat:tt)*))?: $ty:ty| -> $($rest:tt)*) => {
@@ -26,4 +59,85 @@ macro_rules! from_input {
             }
         }
     };
+
+    ($(#[$meta:meta])* $vis:vis enum $name:ident { $($c:literal => $variant:ident),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($variant,)*
+        }
+
+        impl $crate::input::FromInput for $name {
+            #[inline]
+            fn from_input(
+                p: &mut $crate::input::IStr,
+            ) -> core::result::Result<Self, $crate::input::IStrError> {
+                let index = p.index();
+                let c = <char as $crate::input::FromInput>::from_input(p)?;
+
+                match c {
+                    $($c => Ok($name::$variant),)*
+                    c => Err($crate::input::IStrError::new(
+                        index..p.index(),
+                        $crate::input::ErrorKind::Custom($crate::input::Custom::Char(c)),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+/// Define a character type which only accepts one of the listed characters.
+///
+/// The generated tuple struct implements `FromInput` by reading a single
+/// character, and errors with [`ErrorKind::Custom`] containing the character
+/// if it isn't one of the listed ones.
+///
+/// [`ErrorKind::Custom`]: crate::input::ErrorKind::Custom
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+/// use lib::env::Size;
+/// use lib::input::{Custom, ErrorKind};
+///
+/// lib::one_of! {
+///     struct Dir('R', 'U', 'L', 'D');
+/// }
+///
+/// let mut input = IStr::new(b"U 4", Size::ZERO);
+/// let (Dir(d), n) = input.next::<(Dir, u32)>()?;
+/// assert_eq!((d, n), ('U', 4));
+///
+/// let mut input = IStr::new(b"X 4", Size::ZERO);
+/// let error = input.next::<(Dir, u32)>().unwrap_err();
+/// assert!(matches!(error.kind(), ErrorKind::Custom(Custom::Char('X'))));
+/// # Ok::<_, Error>(())
+/// ```
+#[macro_export]
+macro_rules! one_of {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($($c:literal),* $(,)?);) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis struct $name(pub char);
+
+        impl $crate::input::FromInput for $name {
+            #[inline]
+            fn from_input(
+                p: &mut $crate::input::IStr,
+            ) -> core::result::Result<Self, $crate::input::IStrError> {
+                let index = p.index();
+                let c = <char as $crate::input::FromInput>::from_input(p)?;
+
+                match c {
+                    $($c)|* => Ok($name(c)),
+                    c => Err($crate::input::IStrError::new(
+                        index..p.index(),
+                        $crate::input::ErrorKind::Custom($crate::input::Custom::Char(c)),
+                    )),
+                }
+            }
+        }
+    };
 }
@@ -1,10 +1,8 @@
-use std::collections::HashSet;
-use std::ffi::OsString;
 use std::path::PathBuf;
-use std::process::{Command, ExitCode, ExitStatus, Stdio};
+use std::process::{ChildStdout, Command, ExitCode, ExitStatus, Stdio};
 
 use anyhow::{bail, Context, Result};
-use lib::cli::Report;
+use lib::runner::{self, Opts};
 use serde::{de::IntoDeserializer, Deserialize};
 
 const LIB_NAME: &str = env!("CARGO_CRATE_NAME");
@@ -26,67 +24,6 @@ struct Executable {
     path: PathBuf,
 }
 
-#[derive(Default)]
-struct Opts {
-    quiet: bool,
-    verbose: bool,
-    release: bool,
-    no_prod: bool,
-    project: Option<String>,
-    args: Vec<OsString>,
-    names: HashSet<String>,
-}
-
-impl Opts {
-    /// Parse CLI options.
-    pub fn parse() -> Result<Self> {
-        let mut opts = Self::default();
-        let mut it = std::env::args_os().skip(1);
-
-        while let Some(arg) = it.next() {
-            let Some(arg) = arg.to_str() else {
-                bail!("non-utf8 argument");
-            };
-
-            match arg {
-                "-q" | "--quiet" => {
-                    opts.quiet = true;
-                }
-                "-V" | "--verbose" => {
-                    opts.verbose = true;
-                }
-                "-p" => {
-                    let project = it.next().context("missing argument to `-p`")?;
-                    opts.project = Some(project.to_string_lossy().into_owned());
-                }
-                "--release" => {
-                    opts.release = true;
-                }
-                "--no-prod" => {
-                    opts.no_prod = true;
-                }
-                "--" => {
-                    break;
-                }
-                name if !name.starts_with('-') => {
-                    opts.names.insert(name.to_owned());
-                }
-                other => {
-                    bail!("unsupported argument: {other}");
-                }
-            }
-        }
-
-        opts.args.extend(it);
-        Ok(opts)
-    }
-
-    /// Test if options are verbose.
-    fn is_verbose(&self) -> bool {
-        self.verbose && !self.quiet
-    }
-}
-
 fn main() -> Result<ExitCode> {
     let opts = Opts::parse()?;
 
@@ -100,78 +37,38 @@ fn main() -> Result<ExitCode> {
         bail!("no executables!");
     }
 
-    let mut reports = Vec::new();
-    let mut all = ExitCode::SUCCESS;
-
-    for e in executables {
-        if opts.verbose {
-            println!("Running: {}", e.name);
-        }
-
+    let children = executables.into_iter().map(|e| {
         let mut cmd = Command::new(e.path);
         cmd.stdout(Stdio::piped());
         cmd.args(&opts.args[..]);
         cmd.arg("--json");
+        Ok((e.name, Process(cmd.spawn()?)))
+    });
 
-        let mut child = cmd.spawn()?;
-        let output = child.stdout.take().context("missing stdout")?;
-        let output = serde_json::Deserializer::from_reader(output).into_iter();
-
-        for value in output {
-            let Ok(value): Result<serde_json::Value, _> = value else {
-                continue;
-            };
-
-            match value.get("type").and_then(|d| d.as_str()) {
-                Some("report") => {
-                    let report = Data::<Report>::deserialize(value.into_deserializer())?.data;
-                    println!("# {name}", name = e.name);
-                    println!("{report}");
-                    reports.push(report);
-                }
-                Some("message") => {
-                    let message = Data::<Message>::deserialize(value.into_deserializer())?.data;
-
-                    if opts.is_verbose() || message.is_important() {
-                        println!(
-                            "{name}: {kind}: {output}",
-                            name = e.name,
-                            kind = message.kind,
-                            output = message.output
-                        );
-                    }
-                }
-                _ => {}
-            }
-        }
-
-        let status = child.wait()?;
-
-        if !status.success() {
-            all = ExitCode::FAILURE;
-        }
+    let success = runner::run(&opts, children, std::io::stdout().lock())?;
 
-        if opts.is_verbose() {
-            println!("{name}: {status}", name = e.name);
-        }
+    if !success || !status.success() {
+        return Ok(ExitCode::FAILURE);
     }
 
-    if !reports.is_empty() {
-        let mut total = Report::default();
+    Ok(ExitCode::SUCCESS)
+}
+
+/// A solution running as a child process.
+struct Process(std::process::Child);
 
-        for t in &reports {
-            total += t;
-        }
+impl runner::Child for Process {
+    type Output = ChildStdout;
+    type Status = ExitStatus;
 
-        println!("# totals (each sample added together)");
-        println!("{total}");
+    fn output(&mut self) -> Result<Self::Output> {
+        self.0.stdout.take().context("missing stdout")
     }
 
-    if !status.success() {
-        return Ok(ExitCode::FAILURE);
+    fn wait(mut self) -> Result<(Self::Status, bool)> {
+        let status = self.0.wait()?;
+        Ok((status, status.success()))
     }
-
-    Ok(all)
 }
 
 /// Build the project and return status.
@@ -191,7 +88,8 @@ fn build_project(opts: &Opts) -> Result<(Vec<Executable>, ExitStatus)> {
     if let Some(project) = &opts.project {
         cmd.args(["-p", project.as_str()]);
     } else {
-        cmd.arg("--all");
+        // The test days deliberately include failing solutions.
+        cmd.args(["--all", "--exclude", "testing"]);
     }
 
     cmd.args(["--message-format", "json"]);
@@ -238,20 +136,3 @@ fn build_project(opts: &Opts) -> Result<(Vec<Executable>, ExitStatus)> {
     executables.sort_by(|a, b| a.name.cmp(&b.name));
     Ok((executables, status))
 }
-
-#[derive(Deserialize)]
-struct Data<T> {
-    data: T,
-}
-
-#[derive(Deserialize)]
-struct Message {
-    kind: String,
-    output: String,
-}
-
-impl Message {
-    fn is_important(&self) -> bool {
-        matches!(self.kind.as_str(), "error")
-    }
-}
@@ -0,0 +1,451 @@
+//! Math helpers.
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign};
+
+use num::{PrimInt, Unsigned};
+
+/// Compute the greatest common divisor of `a` and `b` using the binary GCD
+/// algorithm.
+///
+/// The greatest common divisor of `0` and `n` is `n`.
+///
+/// # Examples
+///
+/// ```
+/// use lib::math::gcd;
+///
+/// assert_eq!(gcd(48u32, 18), 6);
+/// assert_eq!(gcd(17u64, 5), 1);
+/// assert_eq!(gcd(0u32, 7), 7);
+/// assert_eq!(gcd(7u32, 0), 7);
+/// assert_eq!(gcd(0u32, 0), 0);
+/// ```
+pub fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: PrimInt + Unsigned,
+{
+    if a.is_zero() {
+        return b;
+    }
+
+    if b.is_zero() {
+        return a;
+    }
+
+    let shift = (a | b).trailing_zeros() as usize;
+    a = a >> a.trailing_zeros() as usize;
+
+    loop {
+        b = b >> b.trailing_zeros() as usize;
+
+        if a > b {
+            (a, b) = (b, a);
+        }
+
+        b = b - a;
+
+        if b.is_zero() {
+            return a << shift;
+        }
+    }
+}
+
+/// Compute the least common multiple of `a` and `b`.
+///
+/// The least common multiple of `0` and any number is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use lib::math::lcm;
+///
+/// assert_eq!(lcm(4u32, 6), 12);
+/// assert_eq!(lcm(0u32, 6), 0);
+/// ```
+pub fn lcm<T>(a: T, b: T) -> T
+where
+    T: PrimInt + Unsigned,
+{
+    if a.is_zero() || b.is_zero() {
+        return T::zero();
+    }
+
+    a / gcd(a, b) * b
+}
+
+/// Compute the least common multiple of every number in `iter`, which is `1`
+/// if the iterator is empty.
+///
+/// # Examples
+///
+/// ```
+/// use lib::math::lcm_all;
+///
+/// assert_eq!(lcm_all([4u64, 6, 8]), 24);
+/// assert_eq!(lcm_all([2u64, 3, 5, 7, 11]), 2310);
+/// assert_eq!(lcm_all(Vec::<u32>::new()), 1);
+/// ```
+pub fn lcm_all<I>(iter: I) -> I::Item
+where
+    I: IntoIterator,
+    I::Item: PrimInt + Unsigned,
+{
+    iter.into_iter().fold(num::one(), lcm)
+}
+
+/// Compute the greatest common divisor `g` of `a` and `b` together with
+/// coefficients `x` and `y` such that `a * x + b * y == g`, returned as `(g,
+/// x, y)`.
+///
+/// The returned divisor is never negative.
+///
+/// # Panics
+///
+/// Panics if the divisor doesn't fit in an `i64`, which only happens when it
+/// is `2^63`. That is if `a` and `b` are each either zero or [i64::MIN], but
+/// not both zero.
+///
+/// # Examples
+///
+/// ```
+/// use lib::math::egcd;
+///
+/// let (g, x, y) = egcd(240, 46);
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+///
+/// let (g, x, y) = egcd(-12, 18);
+/// assert_eq!(g, 6);
+/// assert_eq!(-12 * x + 18 * y, 6);
+///
+/// assert_eq!(egcd(0, 0), (0, 1, 0));
+/// assert_eq!(egcd(i64::MIN, 1), (1, 0, 1));
+/// ```
+///
+/// The divisor of [i64::MIN] and zero is out of range:
+///
+/// ```should_panic
+/// use lib::math::egcd;
+///
+/// egcd(i64::MIN, 0);
+/// ```
+#[track_caller]
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd(a as i128, b as i128);
+    let g = i64::try_from(g).expect("greatest common divisor overflows i64");
+    // The coefficients are bounded by the magnitudes of the inputs, so they
+    // always fit.
+    (g, x as i64, y as i64)
+}
+
+/// Compute the modular inverse of `a` modulo `m`, which is the value `x` in
+/// `0..m` such that `a * x % m == 1`.
+///
+/// Returns `None` if `a` and `m` aren't coprime or if `m` is zero, in which
+/// case no inverse exists.
+///
+/// # Examples
+///
+/// ```
+/// use lib::math::mod_inverse;
+///
+/// assert_eq!(mod_inverse(3, 11), Some(4));
+/// assert_eq!(mod_inverse(14, 11), Some(4));
+/// assert_eq!(mod_inverse(u64::MAX - 1, u64::MAX), Some(u64::MAX - 1));
+/// assert_eq!(mod_inverse(6, 9), None);
+/// assert_eq!(mod_inverse(3, 0), None);
+/// ```
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+
+    let m = m as i128;
+    let (g, x, _) = extended_gcd(a as i128 % m, m);
+
+    if g != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(m) as u64)
+}
+
+/// Solve a system of congruences `x ≡ residue (mod modulus)` given as
+/// `(residue, modulus)` pairs using the chinese remainder theorem.
+///
+/// Returns the smallest non-negative solution together with the combined
+/// modulus as `(value, modulus)`, such that every solution is `value + k *
+/// modulus`. The moduli don't have to be coprime.
+///
+/// Returns `None` if the system is inconsistent, if any modulus isn't
+/// positive, or if the combined modulus doesn't fit in an `i64`. An empty
+/// system is solved by `(0, 1)`.
+///
+/// # Examples
+///
+/// ```
+/// use lib::math::crt;
+///
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(-1, 4), (3, 6)]), Some((3, 12)));
+/// assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+/// assert_eq!(crt(&[(1, 0)]), None);
+/// assert_eq!(crt(&[]), Some((0, 1)));
+/// ```
+pub fn crt(residues: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut value = 0i128;
+    let mut modulus = 1i128;
+
+    for &(r, m) in residues {
+        if m <= 0 {
+            return None;
+        }
+
+        let (r, m) = ((r as i128).rem_euclid(m as i128), m as i128);
+        let (g, p, _) = extended_gcd(modulus, m);
+        let diff = r - value;
+
+        if diff % g != 0 {
+            return None;
+        }
+
+        let lcm = modulus / g * m;
+
+        if lcm > i64::MAX as i128 {
+            return None;
+        }
+
+        // Both factors are less than `m / g`, so the product fits in an `i128`.
+        let k = (diff / g).rem_euclid(m / g) * p.rem_euclid(m / g) % (m / g);
+        value = (value + k * modulus).rem_euclid(lcm);
+        modulus = lcm;
+    }
+
+    Some((value as i64, modulus as i64))
+}
+
+/// Iterative extended Euclidean algorithm, see [egcd].
+///
+/// This is computed using `i128` so that it can't overflow for any `i64` or
+/// `u64` arguments.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        return (-old_r, -old_x, -old_y);
+    }
+
+    (old_r, old_x, old_y)
+}
+
+/// An integer modulo the constant `M`, which is reduced after every
+/// operation.
+///
+/// Intermediate results are computed using `u128`, so operations never
+/// overflow for any `M`.
+///
+/// # Examples
+///
+/// ```
+/// use lib::math::Mod;
+///
+/// const M: u64 = 1_000_000_007;
+///
+/// let (a, b) = (u64::MAX - 1, u64::MAX - 2);
+/// let (ma, mb) = (Mod::<M>::new(a), Mod::<M>::new(b));
+///
+/// assert_eq!((ma + mb).get(), (a % M + b % M) % M);
+/// assert_eq!((ma * mb).get(), (a % M) * (b % M) % M);
+///
+/// let mut c = Mod::<M>::new(M - 1);
+/// c += Mod::new(2);
+/// assert_eq!(c.get(), 1);
+/// c *= Mod::new(M + 5);
+/// assert_eq!(c.get(), 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Mod<const M: u64>(u64);
+
+impl<const M: u64> Mod<M> {
+    const VALID: () = assert!(M != 0, "modulus must be non-zero");
+
+    /// Construct a new value, reducing it modulo `M`.
+    #[inline]
+    pub fn new(value: u64) -> Self {
+        let () = Self::VALID;
+        Self(value % M)
+    }
+
+    /// Get the reduced value.
+    #[inline]
+    pub fn get(self) -> u64 {
+        self.0
+    }
+}
+
+impl<const M: u64> Add for Mod<M> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as u128 + rhs.0 as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Mul for Mod<M> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as u128 * rhs.0 as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> AddAssign for Mod<M> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const M: u64> MulAssign for Mod<M> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const M: u64> fmt::Display for Mod<M> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// An integer modulo a modulus only known at runtime, like the product of
+/// every divisor in the input.
+///
+/// Like [Mod], intermediate results are computed using `u128`.
+///
+/// # Panics
+///
+/// Combining two values panics if they use different moduli.
+///
+/// # Examples
+///
+/// ```
+/// use lib::math::ModValue;
+///
+/// let m = 23 * 19 * 13 * 17;
+///
+/// let a = ModValue::new(u64::MAX, m);
+/// let b = ModValue::new(79, m);
+///
+/// assert_eq!((a + b).get(), ((u64::MAX % m) + 79) % m);
+/// assert_eq!((a * b).get(), ((u64::MAX % m) as u128 * 79 % m as u128) as u64);
+/// assert_eq!(b.modulus(), m);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModValue {
+    value: u64,
+    modulus: u64,
+}
+
+impl ModValue {
+    /// Construct a new value, reducing it modulo `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    #[inline]
+    #[track_caller]
+    pub fn new(value: u64, modulus: u64) -> Self {
+        assert!(modulus != 0, "modulus must be non-zero");
+
+        Self {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    /// Get the reduced value.
+    #[inline]
+    pub fn get(self) -> u64 {
+        self.value
+    }
+
+    /// Get the modulus.
+    #[inline]
+    pub fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    #[inline]
+    #[track_caller]
+    fn apply(self, rhs: Self, f: impl FnOnce(u128, u128) -> u128) -> Self {
+        assert_eq!(
+            self.modulus, rhs.modulus,
+            "cannot combine values with different moduli"
+        );
+
+        let value = f(self.value as u128, rhs.value as u128) % self.modulus as u128;
+
+        Self {
+            value: value as u64,
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Add for ModValue {
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.apply(rhs, |a, b| a + b)
+    }
+}
+
+impl Mul for ModValue {
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.apply(rhs, |a, b| a * b)
+    }
+}
+
+impl AddAssign for ModValue {
+    #[inline]
+    #[track_caller]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl MulAssign for ModValue {
+    #[inline]
+    #[track_caller]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl fmt::Display for ModValue {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
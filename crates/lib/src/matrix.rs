@@ -0,0 +1,288 @@
+//! Numeric matrices built on top of [`OwnedGrid`].
+
+use core::fmt;
+use core::ops::{Add, Mul, Sub};
+
+use num::Num;
+
+use crate::grid::{Grid, OwnedGrid};
+
+/// A numeric matrix stored in row-major order.
+///
+/// # Examples
+///
+/// ```
+/// use lib::prelude::*;
+///
+/// let a = Matrix::new(vec![1, 2, 3, 4, 5, 6], 3);
+/// let b = Matrix::new(vec![7, 8, 9, 10, 11, 12], 2);
+///
+/// let c = &a * &b;
+/// assert_eq!(c, Matrix::new(vec![58, 64, 139, 154], 2));
+///
+/// let identity = Matrix::identity(3);
+/// assert_eq!(&a * &identity, a);
+/// assert_eq!(&Matrix::identity(2) * &a, a);
+///
+/// assert_eq!(&a + &a, &a * 2);
+/// assert_eq!(&a - &a, Matrix::zeros(2, 3));
+/// ```
+///
+/// Multiplying matrices with mismatched dimensions panics:
+///
+/// ```should_panic
+/// use lib::prelude::*;
+///
+/// let a = Matrix::new(vec![1, 2, 3, 4, 5, 6], 3);
+/// let _ = &a * &a;
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    grid: OwnedGrid<T>,
+}
+
+impl<T> Matrix<T> {
+    /// Construct a matrix out of row-major `data` where each row has the
+    /// specified number of `columns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` is not a multiple of `columns`.
+    #[inline]
+    #[track_caller]
+    pub fn new(data: Vec<T>, columns: usize) -> Self {
+        Self {
+            grid: OwnedGrid::new(data, columns),
+        }
+    }
+
+    /// Construct a `rows` by `columns` matrix out of row-major `data`,
+    /// preserving the number of rows even if they are empty.
+    #[inline]
+    #[track_caller]
+    fn from_parts(data: Vec<T>, rows: usize, columns: usize) -> Self {
+        Self {
+            grid: OwnedGrid::from_parts(data, rows, columns),
+        }
+    }
+
+    /// Get number of rows in the matrix.
+    #[inline]
+    pub fn rows_len(&self) -> usize {
+        self.grid.rows_len()
+    }
+
+    /// Get number of columns in the matrix.
+    #[inline]
+    pub fn columns_len(&self) -> usize {
+        self.grid.columns_len()
+    }
+
+    /// Access the grid backing the matrix.
+    #[inline]
+    pub fn grid(&self) -> &OwnedGrid<T> {
+        &self.grid
+    }
+
+    /// Coerce the matrix into the grid backing it.
+    #[inline]
+    pub fn into_grid(self) -> OwnedGrid<T> {
+        self.grid
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Num,
+{
+    /// Construct a `rows` by `columns` matrix filled with zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::prelude::*;
+    ///
+    /// let a = Matrix::<u32>::zeros(3, 0);
+    /// assert_eq!(a.rows_len(), 3);
+    /// assert_eq!(a.columns_len(), 0);
+    ///
+    /// let c = &a * &Matrix::zeros(0, 2);
+    /// assert_eq!(c.rows_len(), 3);
+    /// assert_eq!(c.columns_len(), 2);
+    /// assert_eq!(c, Matrix::zeros(3, 2));
+    /// assert_eq!(&a + &a, a);
+    /// assert_eq!(&a * 2, a);
+    /// ```
+    pub fn zeros(rows: usize, columns: usize) -> Self {
+        Self::from_parts(vec![T::zero(); rows * columns], rows, columns)
+    }
+
+    /// Construct an `n` by `n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut data = vec![T::zero(); n * n];
+
+        for i in 0..n {
+            data[i * n + i] = T::one();
+        }
+
+        Self::from_parts(data, n, n)
+    }
+
+    /// Apply `f` elementwise over two matrices of identical dimensions.
+    #[track_caller]
+    fn zip_with(&self, other: &Self, op: &str, f: impl Fn(T, T) -> T) -> Self {
+        assert!(
+            self.rows_len() == other.rows_len() && self.columns_len() == other.columns_len(),
+            "cannot {op} a {}x{} matrix and a {}x{} matrix",
+            self.rows_len(),
+            self.columns_len(),
+            other.rows_len(),
+            other.columns_len(),
+        );
+
+        let data = self
+            .grid
+            .as_slice()
+            .iter()
+            .zip(other.grid.as_slice())
+            .map(|(a, b)| f(*a, *b))
+            .collect();
+
+        Self::from_parts(data, self.rows_len(), self.columns_len())
+    }
+
+    /// Apply `f` to every element in the matrix.
+    fn map(&self, f: impl Fn(T) -> T) -> Self {
+        let data = self.grid.as_slice().iter().map(|a| f(*a)).collect();
+        Self::from_parts(data, self.rows_len(), self.columns_len())
+    }
+}
+
+impl<T> fmt::Debug for Matrix<T>
+where
+    T: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.fmt(f)
+    }
+}
+
+impl<T> From<OwnedGrid<T>> for Matrix<T> {
+    #[inline]
+    fn from(grid: OwnedGrid<T>) -> Self {
+        Self { grid }
+    }
+}
+
+impl<T> Add for &Matrix<T>
+where
+    T: Copy + Num,
+{
+    type Output = Matrix<T>;
+
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, "add", |a, b| a + b)
+    }
+}
+
+impl<T> Sub for &Matrix<T>
+where
+    T: Copy + Num,
+{
+    type Output = Matrix<T>;
+
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, "subtract", |a, b| a - b)
+    }
+}
+
+impl<T> Mul for &Matrix<T>
+where
+    T: Copy + Num,
+{
+    type Output = Matrix<T>;
+
+    /// Naive matrix multiplication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns in `self` doesn't match the number of
+    /// rows in `rhs`.
+    #[track_caller]
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.columns_len() == rhs.rows_len(),
+            "cannot multiply a {}x{} matrix by a {}x{} matrix",
+            self.rows_len(),
+            self.columns_len(),
+            rhs.rows_len(),
+            rhs.columns_len(),
+        );
+
+        let rows = self.rows_len();
+        let columns = rhs.columns_len();
+        let mut data = Vec::with_capacity(rows * columns);
+
+        for r in 0..rows {
+            for c in 0..columns {
+                let mut sum = T::zero();
+
+                for k in 0..self.columns_len() {
+                    sum = sum + *self.grid.get(r, k) * *rhs.grid.get(k, c);
+                }
+
+                data.push(sum);
+            }
+        }
+
+        Matrix::from_parts(data, rows, columns)
+    }
+}
+
+impl<T> Mul<T> for &Matrix<T>
+where
+    T: Copy + Num,
+{
+    type Output = Matrix<T>;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|a| a * rhs)
+    }
+}
+
+macro_rules! owned {
+    ($($trait:ident::$method:ident),*) => {
+        $(
+            impl<T> $trait for Matrix<T>
+            where
+                T: Copy + Num,
+            {
+                type Output = Matrix<T>;
+
+                #[inline]
+                #[track_caller]
+                fn $method(self, rhs: Self) -> Self::Output {
+                    (&self).$method(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+owned!(Add::add, Sub::sub, Mul::mul);
+
+impl<T> Mul<T> for Matrix<T>
+where
+    T: Copy + Num,
+{
+    type Output = Matrix<T>;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        (&self).mul(rhs)
+    }
+}
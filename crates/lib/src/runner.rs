@@ -0,0 +1,304 @@
+//! Helpers for the "run everything" tool.
+
+use core::fmt;
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::io::{Read, Write};
+
+use anyhow::{bail, Context, Result};
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+
+use crate::cli::Report;
+
+/// Options of the "run everything" tool.
+#[derive(Default)]
+pub struct Opts {
+    /// Less verbose output.
+    pub quiet: bool,
+    /// More verbose output.
+    pub verbose: bool,
+    /// Build and run in release mode.
+    pub release: bool,
+    /// Disable production mode in release builds.
+    pub no_prod: bool,
+    /// Stop at the first failing solution.
+    pub fail_fast: bool,
+    /// Only run solutions in the specified project.
+    pub project: Option<String>,
+    /// Arguments passed on to each solution.
+    pub args: Vec<OsString>,
+    /// Only run solutions with the given names.
+    pub names: HashSet<String>,
+}
+
+impl Opts {
+    /// Parse CLI options from the process arguments.
+    pub fn parse() -> Result<Self> {
+        Self::from_args(std::env::args_os().skip(1))
+    }
+
+    /// Parse CLI options from the given arguments, excluding the program name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib::runner::Opts;
+    ///
+    /// let opts = Opts::from_args(["-p", "y2022", "d01", "--", "--bench"].map(Into::into))?;
+    /// assert_eq!(opts.project.as_deref(), Some("y2022"));
+    /// assert!(opts.names.contains("d01"));
+    /// assert_eq!(opts.args, ["--bench"]);
+    ///
+    /// let Err(error) = Opts::from_args(["-p", "y2021", "-p", "y2022"].map(Into::into)) else {
+    ///     panic!("expected error");
+    /// };
+    /// assert_eq!(error.to_string(), "duplicate `-p` arguments");
+    ///
+    /// let Err(error) = Opts::from_args(["--unknown"].map(Into::into)) else {
+    ///     panic!("expected error");
+    /// };
+    /// assert_eq!(error.to_string(), "unsupported argument: --unknown");
+    ///
+    /// assert!(Opts::from_args(["-p"].map(Into::into)).is_err());
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    pub fn from_args<I>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = OsString>,
+    {
+        let mut opts = Self::default();
+        let mut it = args.into_iter();
+
+        while let Some(arg) = it.next() {
+            let Some(arg) = arg.to_str() else {
+                bail!("non-utf8 argument");
+            };
+
+            match arg {
+                "-q" | "--quiet" => {
+                    opts.quiet = true;
+                }
+                "-V" | "--verbose" => {
+                    opts.verbose = true;
+                }
+                "-p" => {
+                    if opts.project.is_some() {
+                        bail!("duplicate `-p` arguments");
+                    }
+
+                    let project = it.next().context("missing argument to `-p`")?;
+                    opts.project = Some(project.to_string_lossy().into_owned());
+                }
+                "--release" => {
+                    opts.release = true;
+                }
+                "--no-prod" => {
+                    opts.no_prod = true;
+                }
+                "--fail-fast" => {
+                    opts.fail_fast = true;
+                }
+                "--" => {
+                    break;
+                }
+                name if !name.starts_with('-') => {
+                    opts.names.insert(name.to_owned());
+                }
+                other => {
+                    bail!("unsupported argument: {other}");
+                }
+            }
+        }
+
+        opts.args.extend(it);
+        Ok(opts)
+    }
+
+    /// Test if options are verbose.
+    pub fn is_verbose(&self) -> bool {
+        self.verbose && !self.quiet
+    }
+}
+
+/// A solution being run by [run].
+pub trait Child {
+    /// The JSON output of the solution.
+    type Output: Read;
+    /// The exit status of the solution.
+    type Status: fmt::Display;
+
+    /// Take the output of the solution.
+    fn output(&mut self) -> Result<Self::Output>;
+
+    /// Wait for the solution to exit, returning its status and whether it
+    /// was successful.
+    fn wait(self) -> Result<(Self::Status, bool)>;
+}
+
+/// Run the named `children` in order, writing their reports and any important
+/// messages to `out` followed by the totals of all reports.
+///
+/// Children are pulled out of the iterator one at a time, so with
+/// `--fail-fast` nothing after the first failing solution is run. A solution
+/// fails if it exits unsuccessfully or reports an error.
+///
+/// Returns `true` if every solution exited successfully.
+///
+/// # Examples
+///
+/// ```
+/// use lib::runner::{self, Child, Opts};
+///
+/// struct Fake(&'static str, bool);
+///
+/// impl Child for Fake {
+///     type Output = &'static [u8];
+///     type Status = bool;
+///
+///     fn output(&mut self) -> anyhow::Result<Self::Output> {
+///         Ok(self.0.as_bytes())
+///     }
+///
+///     fn wait(self) -> anyhow::Result<(Self::Status, bool)> {
+///         Ok((self.1, self.1))
+///     }
+/// }
+///
+/// const PASS: Fake = Fake("", true);
+/// const FAIL: Fake = Fake("", false);
+/// const ERROR: Fake = Fake(r#"{"type":"message","data":{"kind":"error","output":"boom"}}"#, true);
+///
+/// /// Spawn `children` lazily, recording their names in `spawned`.
+/// fn spawn<'a, const N: usize>(
+///     spawned: &'a mut Vec<&'static str>,
+///     children: [(&'static str, Fake); N],
+/// ) -> impl Iterator<Item = anyhow::Result<(String, Fake)>> + 'a {
+///     spawned.clear();
+///
+///     children.into_iter().map(|(name, child)| {
+///         spawned.push(name);
+///         Ok((name.to_owned(), child))
+///     })
+/// }
+///
+/// let mut spawned = Vec::new();
+///
+/// // A reported error stops the run.
+/// let opts = Opts::from_args(["--fail-fast"].map(Into::into))?;
+/// let mut out = Vec::new();
+/// let it = spawn(&mut spawned, [("a", PASS), ("b", ERROR), ("c", PASS)]);
+/// assert!(!runner::run(&opts, it, &mut out)?);
+/// assert_eq!(spawned, ["a", "b"]);
+/// assert_eq!(
+///     String::from_utf8(out)?,
+///     "b: error: boom\nb: failed, stopping due to --fail-fast\n"
+/// );
+///
+/// // So does a failing exit status.
+/// let it = spawn(&mut spawned, [("a", FAIL), ("b", PASS), ("c", PASS)]);
+/// assert!(!runner::run(&opts, it, std::io::sink())?);
+/// assert_eq!(spawned, ["a"]);
+///
+/// // Without `--fail-fast` everything is run.
+/// let opts = Opts::from_args([])?;
+/// let it = spawn(&mut spawned, [("a", FAIL), ("b", ERROR), ("c", PASS)]);
+/// assert!(!runner::run(&opts, it, std::io::sink())?);
+/// assert_eq!(spawned, ["a", "b", "c"]);
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub fn run<I, C, O>(opts: &Opts, children: I, mut out: O) -> Result<bool>
+where
+    I: IntoIterator<Item = Result<(String, C)>>,
+    C: Child,
+    O: Write,
+{
+    let mut reports = Vec::new();
+    let mut all = true;
+
+    for child in children {
+        let (name, mut child) = child?;
+
+        if opts.verbose {
+            writeln!(out, "Running: {name}")?;
+        }
+
+        let output = serde_json::Deserializer::from_reader(child.output()?).into_iter();
+        let mut errored = false;
+
+        for value in output {
+            let Ok(value): Result<serde_json::Value, _> = value else {
+                continue;
+            };
+
+            match value.get("type").and_then(|d| d.as_str()) {
+                Some("report") => {
+                    let report = Data::<Report>::deserialize(value.into_deserializer())?.data;
+                    writeln!(out, "# {name}")?;
+                    writeln!(out, "{report}")?;
+                    reports.push(report);
+                }
+                Some("message") => {
+                    let message = Data::<Message>::deserialize(value.into_deserializer())?.data;
+                    errored |= message.is_error();
+
+                    if opts.is_verbose() || message.is_important() {
+                        writeln!(
+                            out,
+                            "{name}: {kind}: {output}",
+                            kind = message.kind,
+                            output = message.output
+                        )?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let (status, success) = child.wait()?;
+        all &= success;
+
+        if opts.is_verbose() {
+            writeln!(out, "{name}: {status}")?;
+        }
+
+        if opts.fail_fast && (errored || !success) {
+            writeln!(out, "{name}: failed, stopping due to --fail-fast")?;
+            return Ok(false);
+        }
+    }
+
+    if !reports.is_empty() {
+        let mut total = Report::default();
+
+        for t in &reports {
+            total += t;
+        }
+
+        writeln!(out, "# totals (each sample added together)")?;
+        writeln!(out, "{total}")?;
+    }
+
+    Ok(all)
+}
+
+#[derive(Deserialize)]
+struct Data<T> {
+    data: T,
+}
+
+#[derive(Deserialize)]
+struct Message {
+    kind: String,
+    output: String,
+}
+
+impl Message {
+    fn is_important(&self) -> bool {
+        self.is_error()
+    }
+
+    fn is_error(&self) -> bool {
+        matches!(self.kind.as_str(), "error")
+    }
+}
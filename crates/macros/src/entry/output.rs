@@ -88,12 +88,22 @@ impl ItemOutput {
             _ => Compare::Ignore,
         };
 
-        let call_mode = (
-            (mode, S, "Default"),
-            T,
+        let repeat = (
+            "for",
+            "_",
+            "in",
+            (0usize, ['.', '.'], "opts", '.', "repeat", parens(())),
             braced(CollectCall(fn_name.clone(), input_arg, m, compare)),
         );
 
+        let call_mode = ((mode, S, "Default"), T, braced(repeat));
+
+        let check_mode = (
+            (mode, S, "Check"),
+            T,
+            braced(CheckCall(fn_name.clone(), input_arg, m, compare)),
+        );
+
         let bench_mode = (
             (mode, S, "Bench"),
             T,
@@ -103,13 +113,14 @@ impl ItemOutput {
         let match_mode = (
             "match",
             ("opts", '.', "mode"),
-            braced((call_mode, bench_mode)),
+            braced((call_mode, bench_mode, check_mode)),
             ';',
         );
 
-        let ok_return = ("Ok", parens(parens(())));
+        let exit_code = ("std", S, "process", S, "ExitCode");
+        let ok_return = ("Ok", parens((exit_code, S, "SUCCESS")));
 
-        let anyhow_result = ("lib", S, "prelude", S, "Result", '<', parens(()), '>');
+        let anyhow_result = ("lib", S, "prelude", S, "Result", '<', exit_code, '>');
         let signature = ("fn", "main", parens(()), ['-', '>'], anyhow_result);
 
         let original = TokenTree::Group(Group::new(Delimiter::None, item_stream));
@@ -166,33 +177,48 @@ impl IntoTokens for Input {
     }
 }
 
+/// Call the solution, returning early with context if it errors.
+fn call(name: TokenTree, input: Input, m: Mod) -> impl IntoTokens {
+    let handle_error = from_fn(move |s| {
+        let error = (
+            (m, "cli", S, "error_context"),
+            parens(("path", ',', "input", ',', "error")),
+        );
+        s.write(("return", "Err"));
+        s.write(parens((error, '.', "into", parens(()))));
+    });
+
+    from_fn(move |s| {
+        s.write("match");
+        s.write((name, parens(input)));
+        s.write(braced(from_fn(move |s| {
+            s.write(("Ok", parens("value"), T, "value", ','));
+            s.write(("Err", parens("error"), T, handle_error, ','));
+        })));
+    })
+}
+
 struct CollectCall<'a>(TokenTree, Input, Mod, Compare<'a>);
 
 impl IntoTokens for CollectCall<'_> {
     fn into_tokens(self, stream: &mut TokenStream, span: Span) {
         let CollectCall(name, input, m, compare) = self;
+        let call = call(name, input, m);
+        stream.write(span, ("let", compare.binding(), '=', call, ';'));
+        stream.write(span, compare);
+    }
+}
 
-        let handle_error = from_fn(|s| {
-            let error = (
-                (m, "cli", S, "error_context"),
-                parens(("path", ',', "input", ',', "error")),
-            );
-            s.write(("return", "Err"));
-            s.write(parens((error, '.', "into", parens(()))));
-        });
-
-        let handle_error = from_fn(move |s| {
-            s.write("match");
-            s.write((name, parens(input)));
-            s.write(braced(from_fn(move |s| {
-                s.write(("Ok", parens("value"), T, "value", ','));
-                s.write(("Err", parens("error"), T, handle_error, ','));
-            })));
-        });
+struct CheckCall<'a>(TokenTree, Input, Mod, Compare<'a>);
 
-        stream.write(span, ("let", compare.binding(), '=', handle_error, ';'));
+impl IntoTokens for CheckCall<'_> {
+    fn into_tokens(self, stream: &mut TokenStream, span: Span) {
+        let CheckCall(name, input, m, compare) = self;
+        stream.write(span, ("let", "value", '=', call(name, input, m), ';'));
 
-        stream.write(span, compare);
+        let checker = (m, "cli", S, "Checker", S, "new", parens(('&', "opts")));
+        let check = ("check", parens((compare.expect(), ',', "value")), '?');
+        stream.write(span, ("return", "Ok", parens((checker, '.', check)), ';'));
     }
 }
 
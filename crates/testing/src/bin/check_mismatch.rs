@@ -0,0 +1,7 @@
+use lib::prelude::*;
+
+/// The expected value is deliberately wrong.
+#[entry(input = "numbers.txt", expect = 7)]
+fn main(input: IStr) -> Result<u32> {
+    Ok(input.iter::<u32>().sum::<Result<_, _>>()?)
+}
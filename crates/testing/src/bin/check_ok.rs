@@ -0,0 +1,6 @@
+use lib::prelude::*;
+
+#[entry(input = "numbers.txt", expect = 6)]
+fn main(input: IStr) -> Result<u32> {
+    Ok(input.iter::<u32>().sum::<Result<_, _>>()?)
+}
@@ -0,0 +1,13 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use lib::prelude::*;
+
+/// The number of times the solution has been run.
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+#[entry(input = "numbers.txt", expect = 6)]
+fn main(input: IStr) -> Result<u32> {
+    let run = RUNS.fetch_add(1, Ordering::Relaxed) + 1;
+    eprintln!("run {run}");
+    Ok(input.iter::<u32>().sum::<Result<_, _>>()?)
+}
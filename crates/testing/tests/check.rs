@@ -0,0 +1,28 @@
+use std::process::Command;
+
+#[test]
+fn check_ok() {
+    let output = Command::new(env!("CARGO_BIN_EXE_check_ok"))
+        .arg("--check")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("OK: 6"), "{stdout}");
+}
+
+#[test]
+fn check_mismatch() {
+    let output = Command::new(env!("CARGO_BIN_EXE_check_mismatch"))
+        .arg("--check")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success(), "{stdout}");
+    assert!(
+        stdout.contains("MISMATCH: 6 (value) != 7 (expected)"),
+        "{stdout}"
+    );
+}
@@ -0,0 +1,76 @@
+use std::process::Command;
+
+fn stdout(bin: &str, args: &[&str]) -> String {
+    let output = Command::new(bin).args(args).output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn color_never() {
+    let check_ok = env!("CARGO_BIN_EXE_check_ok");
+    let check_mismatch = env!("CARGO_BIN_EXE_check_mismatch");
+
+    assert_eq!(
+        stdout(check_ok, &["--check", "--color", "never"]),
+        "info: OK: 6\n"
+    );
+
+    assert_eq!(
+        stdout(check_mismatch, &["--check", "--no-color"]),
+        "error: MISMATCH: 6 (value) != 7 (expected)\n"
+    );
+
+    let out = stdout(
+        check_ok,
+        &[
+            "--bench", "--iter", "1", "--warmup", "0", "--color", "never",
+        ],
+    );
+    let lines = out.lines().collect::<Vec<_>>();
+    assert!(!out.contains('\x1b'), "{out}");
+    assert_eq!(lines[0], "info: running benches (1 iterations)...");
+    assert!(lines[1].starts_with("count: 1, min: "), "{out}");
+    assert!(lines[2].starts_with("25th: "), "{out}");
+}
+
+#[test]
+fn color_always() {
+    let check_ok = env!("CARGO_BIN_EXE_check_ok");
+    let check_mismatch = env!("CARGO_BIN_EXE_check_mismatch");
+
+    assert_eq!(
+        stdout(check_ok, &["--check", "--color", "always"]),
+        "\x1b[32minfo\x1b[0m: OK: 6\n"
+    );
+
+    assert_eq!(
+        stdout(check_mismatch, &["--check", "--color", "always"]),
+        "\x1b[31merror\x1b[0m: MISMATCH: 6 (value) != 7 (expected)\n"
+    );
+
+    let out = stdout(
+        check_ok,
+        &[
+            "--bench", "--iter", "1", "--warmup", "0", "--color", "always",
+        ],
+    );
+    let lines = out.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines[0],
+        "\x1b[32minfo\x1b[0m: running benches (1 iterations)..."
+    );
+    assert!(
+        lines[1].starts_with("\x1b[1mcount\x1b[0m: 1, \x1b[1mmin\x1b[0m: "),
+        "{out}"
+    );
+    assert!(lines[2].starts_with("\x1b[1m25th\x1b[0m: "), "{out}");
+}
+
+#[test]
+fn color_json() {
+    let out = stdout(
+        env!("CARGO_BIN_EXE_check_ok"),
+        &["--check", "--json", "--color", "always"],
+    );
+    assert!(!out.contains('\x1b'), "{out}");
+}
@@ -0,0 +1,23 @@
+use std::process::Command;
+
+fn runs(args: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_repeat"))
+        .args(args)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    stderr.lines().map(str::to_owned).collect()
+}
+
+#[test]
+fn repeat_default() {
+    assert_eq!(runs(&[]), ["run 1"]);
+}
+
+#[test]
+fn repeat_n() {
+    assert_eq!(runs(&["--repeat", "3"]), ["run 1", "run 2", "run 3"]);
+}
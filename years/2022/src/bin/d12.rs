@@ -60,18 +60,16 @@ where
     C: Fn(u8, u8) -> bool,
     E: Fn(u8) -> bool,
 {
-    let mut heap = FixedHeap::<_, HEAP_CAP>::new();
-
-    let comparer = |a: &Element, b: &Element, _: &()| a.0 < b.0;
+    let mut heap = MinHeap::<_, HEAP_CAP, _>::by_key(|e: &Element| e.0);
 
     visited.clear_bits();
 
     if let Some(&b) = grid.try_get(start.1, start.0) {
-        heap.push((0u32, start, to_cost(b)), &comparer, &());
+        heap.push((0u32, start, to_cost(b)));
         visited.set_bit(index(start));
     }
 
-    while let Some((cost, pos, e)) = heap.pop(&comparer, &()) {
+    while let Some((cost, pos, e)) = heap.pop() {
         for (pos, &d) in neigh(pos).flat_map(|(x, y)| Some(((x, y), grid.try_get(y, x)?))) {
             let c = to_cost(d);
 
@@ -85,7 +83,7 @@ where
                 return Ok(cost + 1);
             }
 
-            if heap.push((cost + 1, pos, c), &comparer, &()).is_some() {
+            if heap.push((cost + 1, pos, c)).is_some() {
                 anyhow::bail!("out of heap capacity");
             }
         }